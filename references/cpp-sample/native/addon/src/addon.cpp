@@ -0,0 +1 @@
+int addon_version() { return 1; }
@@ -0,0 +1 @@
+int codec_version() { return 4; }
@@ -0,0 +1,3 @@
+defmodule Notifier do
+  def hello, do: :world
+end
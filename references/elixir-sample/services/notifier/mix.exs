@@ -0,0 +1,35 @@
+defmodule Notifier.MixProject do
+  use Mix.Project
+
+  @version "0.9.1"
+
+  def project do
+    [
+      app: :notifier,
+      version: @version,
+      elixir: "~> 1.17",
+      start_permanent: Mix.env() == :prod,
+      deps: deps(),
+      aliases: aliases()
+    ]
+  end
+
+  def application do
+    [extra_applications: [:logger], mod: {Notifier.Application, []}]
+  end
+
+  defp deps do
+    [
+      {:phoenix, "~> 1.7"},
+      {:credo, "~> 1.7", only: [:dev, :test], runtime: false}
+    ]
+  end
+
+  defp aliases do
+    [
+      setup: ["deps.get", "ecto.setup"],
+      "ecto.reset": ["ecto.drop", "ecto.setup"],
+      lint: ["format --check-formatted", "credo --strict"]
+    ]
+  end
+end
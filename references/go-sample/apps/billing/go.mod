@@ -0,0 +1,3 @@
+module github.com/acme/backend/apps/billing/v2
+
+go 1.22
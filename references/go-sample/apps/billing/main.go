@@ -0,0 +1,5 @@
+package main
+
+//go:generate stringer -type=Currency
+
+func main() {}
@@ -0,0 +1,4 @@
+// Gateway service
+module github.com/acme/backend/apps/gateway
+
+go 1.22
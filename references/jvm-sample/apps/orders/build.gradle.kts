@@ -0,0 +1,17 @@
+plugins {
+    java
+    checkstyle
+    id("com.diffplug.spotless") version "6.25.0"
+}
+
+group = "com.acme"
+
+repositories {
+    mavenCentral()
+}
+
+spotless {
+    java {
+        googleJavaFormat()
+    }
+}
@@ -0,0 +1,7 @@
+require "rails/all"
+
+module Storefront
+  class Application < Rails::Application
+    config.load_defaults 8.0
+  end
+end
@@ -0,0 +1,3 @@
+module Money
+  VERSION = "1.4.2"
+end
@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+
+use clap::{Args, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use mrt::graph::PackageGraph;
+
+use super::{CommandExec, CommandResult};
+
+/// In-repo dependencies of every package, as adjacency lists keyed by package name
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct GraphResult {
+    dependencies: BTreeMap<String, Vec<String>>,
+    /// Path of every cycle, from a package back to itself
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    cycles: Vec<Vec<String>>,
+}
+
+impl CommandResult<GraphResult> for GraphResult {
+    fn get_result(&self) -> &GraphResult {
+        self
+    }
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+pub enum GraphFormat {
+    Dot,
+    Mermaid,
+    Json,
+}
+
+#[derive(Args)]
+pub struct GraphArgs {
+    /// Package name or path to focus on, the whole graph is rendered when omitted
+    #[arg(index = 1)]
+    pub package: Option<String>,
+    /// Format of the rendered graph
+    #[arg(short, long, value_enum, default_value_t = GraphFormat::Dot)]
+    pub format: GraphFormat,
+    /// Only packages at most this many dependencies away from the focused package
+    #[arg(long, requires = "package")]
+    pub depth: Option<usize>,
+    /// Include packages that depend on the focused package
+    #[arg(long, default_value_t = false, requires = "package")]
+    pub dependents: bool,
+    /// Include packages the focused package depends on
+    #[arg(long, default_value_t = false, requires = "package")]
+    pub dependencies: bool,
+}
+
+/// Graph being rendered: selected packages and dependencies between them
+pub(crate) struct GraphView<'a> {
+    graph: &'a PackageGraph,
+    nodes: Vec<usize>,
+    cycles: Vec<Vec<usize>>,
+}
+
+impl GraphView<'_> {
+    fn get_name(&self, index: usize) -> &str {
+        self.graph.get_packages()[index].name.as_str()
+    }
+
+    fn get_edges(&self) -> Vec<(usize, usize)> {
+        self.nodes
+            .iter()
+            .flat_map(|&from| {
+                self.graph
+                    .get_dependencies(from)
+                    .iter()
+                    .filter(|to| self.nodes.contains(to))
+                    .map(move |&to| (from, to))
+            })
+            .collect()
+    }
+
+    pub(crate) fn to_result(&self) -> GraphResult {
+        let mut dependencies: BTreeMap<String, Vec<String>> = self
+            .nodes
+            .iter()
+            .map(|&node| (self.get_name(node).to_string(), vec![]))
+            .collect();
+        for (from, to) in self.get_edges() {
+            if let Some(names) = dependencies.get_mut(self.get_name(from)) {
+                names.push(self.get_name(to).to_string());
+            }
+        }
+
+        let cycles = self
+            .cycles
+            .iter()
+            .filter(|cycle| cycle.iter().any(|node| self.nodes.contains(node)))
+            .map(|cycle| {
+                self.graph
+                    .get_cycle_path(cycle)
+                    .iter()
+                    .map(|&node| self.get_name(node).to_string())
+                    .collect()
+            })
+            .collect();
+
+        GraphResult {
+            dependencies,
+            cycles,
+        }
+    }
+
+    pub(crate) fn to_dot(&self) -> String {
+        let quote = |name: &str| format!("\"{}\"", name.replace('"', "\\\""));
+
+        let mut lines = vec!["digraph packages {".to_string()];
+        for &node in &self.nodes {
+            lines.push(format!("    {};", quote(self.get_name(node))));
+        }
+        for (from, to) in self.get_edges() {
+            let style = match self.graph.is_cycle_edge(&self.cycles, from, to) {
+                true => " [color=red]",
+                false => "",
+            };
+            lines.push(format!(
+                "    {} -> {}{};",
+                quote(self.get_name(from)),
+                quote(self.get_name(to)),
+                style
+            ));
+        }
+        lines.push("}".to_string());
+
+        lines.join("\n")
+    }
+
+    pub(crate) fn to_mermaid(&self) -> String {
+        // Package names are not valid Mermaid ids, so nodes are numbered
+        let mut lines = vec!["graph LR".to_string()];
+        for &node in &self.nodes {
+            lines.push(format!(
+                "    n{}[\"{}\"]",
+                node,
+                self.get_name(node).replace('"', "#quot;")
+            ));
+        }
+
+        let mut cycle_links = vec![];
+        for (link, (from, to)) in self.get_edges().into_iter().enumerate() {
+            lines.push(format!("    n{} --> n{}", from, to));
+            if self.graph.is_cycle_edge(&self.cycles, from, to) {
+                cycle_links.push(link.to_string());
+            }
+        }
+        if !cycle_links.is_empty() {
+            lines.push(format!(
+                "    linkStyle {} stroke:red",
+                cycle_links.join(",")
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
+
+impl GraphArgs {
+    /// Packages selected by the arguments, `None` when the focused package does not exist
+    pub(crate) fn get_view<'a>(&self, graph: &'a PackageGraph) -> Option<GraphView<'a>> {
+        let nodes = match &self.package {
+            // Both directions, unless one of them is asked for
+            Some(package) => graph.select(
+                graph.find_package(package)?,
+                self.depth,
+                self.dependencies || !self.dependents,
+                self.dependents || !self.dependencies,
+            ),
+            None => (0..graph.get_packages().len()).collect(),
+        };
+
+        Some(GraphView {
+            graph,
+            nodes,
+            cycles: graph.get_cycles(),
+        })
+    }
+}
+
+impl CommandExec<GraphResult> for GraphArgs {
+    fn exec(
+        &self,
+        context: &impl super::CommandExecutionContext,
+    ) -> Box<dyn CommandResult<GraphResult>> {
+        let graph = context.get_project().get_package_graph();
+        let Some(view) = self.get_view(&graph) else {
+            eprintln!(
+                "Package `{}` not found",
+                self.package.as_deref().unwrap_or_default()
+            );
+            std::process::exit(1);
+        };
+        let result = view.to_result();
+
+        if context.get_cli().is_interactive() {
+            match self.format {
+                GraphFormat::Dot => println!("{}", view.to_dot()),
+                GraphFormat::Mermaid => println!("{}", view.to_mermaid()),
+                GraphFormat::Json => println!("{}", serde_json::to_string_pretty(&result).unwrap()),
+            }
+        }
+
+        Box::from(result)
+    }
+}
@@ -0,0 +1,80 @@
+use clap::Args;
+use serde::{Deserialize, Serialize};
+use tabled::{builder::Builder, settings::Style};
+
+use mrt::package::Package;
+
+use super::{CommandExec, CommandResult};
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InfoResult {
+    package: Package,
+}
+
+impl CommandResult<InfoResult> for InfoResult {
+    fn get_result(&self) -> &InfoResult {
+        self
+    }
+}
+
+#[derive(Args)]
+pub struct InfoArgs {
+    /// Package name, or package path relative to the project root
+    #[arg(index = 1)]
+    pub package: String,
+}
+
+impl CommandExec<InfoResult> for InfoArgs {
+    fn exec(
+        &self,
+        context: &impl super::CommandExecutionContext,
+    ) -> Box<dyn CommandResult<InfoResult>> {
+        let Some(package) = context
+            .get_project()
+            .get_packages(true)
+            .into_iter()
+            .find(|package| package.matches_name_or_path(&self.package))
+        else {
+            eprintln!("Package `{}` not found", self.package);
+            std::process::exit(1);
+        };
+
+        if context.get_cli().is_interactive() {
+            let metadata = &package.metadata;
+            let private = match metadata.private {
+                Some(true) => "yes",
+                Some(false) => "no",
+                None => "",
+            };
+
+            let mut builder = Builder::default();
+            builder.push_record(["name", package.name.as_str()]);
+            builder.push_record(["version", package.version.as_str()]);
+            builder.push_record(["path", package.path.as_str()]);
+            builder.push_record(["archetype", package.archetype_ids.join(", ").as_str()]);
+            builder.push_record(["tags", package.tags.join(", ").as_str()]);
+            builder.push_record(["owners", package.owners.join(", ").as_str()]);
+            builder.push_record(["status", package.status.to_string().as_str()]);
+            builder.push_record([
+                "description",
+                metadata.description.as_deref().unwrap_or_default(),
+            ]);
+            builder.push_record(["private", private]);
+            builder.push_record(["license", metadata.license.as_deref().unwrap_or_default()]);
+            builder.push_record(["authors", metadata.authors.join(", ").as_str()]);
+            builder.push_record([
+                "repository",
+                metadata.repository.as_deref().unwrap_or_default(),
+            ]);
+            builder.push_record(["keywords", metadata.keywords.join(", ").as_str()]);
+            builder.push_record(["scripts", metadata.scripts.join(", ").as_str()]);
+
+            let mut table = builder.build();
+            table.with(Style::blank());
+
+            println!("{}", table);
+        }
+
+        Box::from(InfoResult { package })
+    }
+}
@@ -4,7 +4,7 @@ use tabled::{builder::Builder, settings::Style};
 
 use mrt::package::Package;
 
-use super::{CommandExec, CommandResult};
+use super::{CommandExec, CommandResult, PackageFilterArgs};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ListResult {
@@ -21,6 +21,8 @@ impl CommandResult<ListResult> for ListResult {
 pub struct ListArgs {
     #[arg(short, long, default_value_t = false)]
     pub all: bool,
+    #[command(flatten)]
+    pub filter: PackageFilterArgs,
 }
 
 impl CommandExec<ListResult> for ListArgs {
@@ -28,19 +30,31 @@ impl CommandExec<ListResult> for ListArgs {
         &self,
         context: &impl super::CommandExecutionContext,
     ) -> Box<dyn CommandResult<ListResult>> {
-        let packages = context.get_project().get_packages(self.all);
+        let packages = self
+            .filter
+            .apply(context.get_project().get_packages(self.all));
 
         let result = ListResult { packages };
 
         if context.get_cli().is_interactive() {
             let mut builder = Builder::default();
-            builder.push_record(["name", "version", "path", "archetype", "status"]);
+            builder.push_record([
+                "name",
+                "version",
+                "path",
+                "archetype",
+                "tags",
+                "owners",
+                "status",
+            ]);
             for package in &result.packages {
                 builder.push_record([
                     package.name.as_str(),
                     package.version.as_str(),
                     package.path.as_str(),
-                    package.archetype_id.as_str(),
+                    package.archetype_ids.join(", ").as_str(),
+                    package.tags.join(", ").as_str(),
+                    package.owners.join(", ").as_str(),
                     package.status.to_string().as_str(),
                 ]);
             }
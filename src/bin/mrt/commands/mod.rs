@@ -1,10 +1,18 @@
+use clap::Args;
 use indicatif::ProgressBar;
 use serde::ser;
 
-use mrt::{progress::ProgressReporter, project::Project};
+use mrt::{
+    graph::{PackageGraph, PackageSelector},
+    package::Package,
+    progress::ProgressReporter,
+    project::Project,
+};
 
 use crate::Cli;
 
+pub mod graph;
+pub mod info;
 pub mod list;
 pub mod run_script;
 
@@ -27,6 +35,66 @@ where
     fn get_result(&self) -> &T;
 }
 
+/// Package selection shared by commands working on several packages
+#[derive(Args, Default)]
+pub struct PackageFilterArgs {
+    /// Only packages with this tag, can be repeated to match any of the tags
+    #[arg(long = "tag")]
+    pub tags: Vec<String>,
+    /// Only packages owned by this owner, can be repeated to match any of the owners
+    #[arg(long = "owner")]
+    pub owners: Vec<String>,
+    /// Only packages matched by the selector: `name`, `name...` with its dependencies,
+    /// `...name` with its dependents, `^` excludes the package itself, like `name^...`.
+    /// Can be repeated to match any of the selectors
+    #[arg(long = "filter")]
+    pub filters: Vec<String>,
+}
+
+impl PackageFilterArgs {
+    pub fn matches(&self, package: &Package) -> bool {
+        let matches_any = |filter: &[String], values: &[String]| {
+            filter.is_empty() || filter.iter().any(|value| values.contains(value))
+        };
+
+        matches_any(&self.tags, &package.tags) && matches_any(&self.owners, &package.owners)
+    }
+
+    pub fn apply(&self, packages: Vec<Package>) -> Vec<Package> {
+        let packages = self.apply_selectors(packages);
+
+        packages
+            .into_iter()
+            .filter(|package| self.matches(package))
+            .collect()
+    }
+
+    /// Selectors follow dependencies, so they are applied before other filters
+    fn apply_selectors(&self, packages: Vec<Package>) -> Vec<Package> {
+        if self.filters.is_empty() {
+            return packages;
+        }
+
+        let graph = PackageGraph::new(packages);
+        let mut selected = vec![false; graph.get_packages().len()];
+        for filter in &self.filters {
+            let indices = graph.select_packages(&PackageSelector::parse(filter));
+            if indices.is_empty() {
+                eprintln!("No packages match filter `{}`", filter);
+            }
+            indices.into_iter().for_each(|index| selected[index] = true);
+        }
+
+        graph
+            .get_packages()
+            .iter()
+            .zip(selected)
+            .filter(|(_, selected)| *selected)
+            .map(|(package, _)| package.clone())
+            .collect()
+    }
+}
+
 pub(super) struct ProgressBarReporter<'a> {
     pub progress_bar: &'a ProgressBar,
 }
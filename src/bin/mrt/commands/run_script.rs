@@ -1,18 +1,25 @@
 use std::thread;
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::Args;
 use console::style;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 use serde::{Deserialize, Serialize};
+use tabled::{builder::Builder, settings::Style};
 
-use mrt::archetypes::get_archetype_by_id;
+use mrt::graph::PackageGraph;
 use mrt::package::Package;
 use mrt::progress::ProgressReporter;
-use mrt::runners::{ScriptRunContext, ScriptRunResult, ScriptRunResultType};
+use mrt::project::Project;
+use mrt::runners::{
+    ScriptRunContext, ScriptRunExplanation, ScriptRunResult, ScriptRunResultType, ScriptRunner,
+    ScriptRunnerCandidate,
+};
 
-use super::{CommandExec, CommandResult, NoopProgressReporter, ProgressBarReporter};
+use super::{
+    CommandExec, CommandResult, NoopProgressReporter, PackageFilterArgs, ProgressBarReporter,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct PackageResult<TResult> {
@@ -22,7 +29,9 @@ pub struct PackageResult<TResult> {
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct RunScriptResult {
-    results: Vec<PackageResult<ScriptRunResult>>,
+    pub results: Vec<PackageResult<ScriptRunResult>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    explanations: Vec<PackageResult<ScriptRunExplanation>>,
 }
 
 impl CommandResult<RunScriptResult> for RunScriptResult {
@@ -36,101 +45,224 @@ pub struct RunScriptArgs {
     /// Name of the script to run
     #[arg(index = 1)]
     pub script_spec: String,
+    /// Arguments forwarded to the script, after `--`
+    #[arg(index = 2, last = true)]
+    pub script_args: Vec<String>,
+    /// Report which runner would execute the script in each package, without running it
+    #[arg(long, default_value_t = false)]
+    pub explain: bool,
+    /// Run packages that depend on each other together instead of refusing to run
+    #[arg(long, default_value_t = false)]
+    pub allow_cycles: bool,
+    #[command(flatten)]
+    pub filter: PackageFilterArgs,
 }
 
+fn get_script_runner(package: &Package, project: &Project) -> Result<Box<dyn ScriptRunner>> {
+    project
+        .get_script_runner(package)
+        .with_context(|| format!("No script runner for package {}", package.name))
+}
+
+/// Failures to start the script, like a missing tool, are reported as the package result
 fn exec_package(
     package: &Package,
+    project: &Project,
     script_spec: &str,
+    script_args: &[String],
     reporter: &impl ProgressReporter,
-) -> Result<ScriptRunResult> {
-    let script_runner = get_archetype_by_id(package.archetype_id.as_str())
-        .unwrap()
-        .get_script_runner();
-
-    script_runner.run_script(&ScriptRunContext {
-        script_spec,
-        package,
-        reporter,
-    })
+) -> ScriptRunResult {
+    get_script_runner(package, project)
+        .and_then(|script_runner| {
+            script_runner.run_script(&ScriptRunContext {
+                script_spec,
+                script_args,
+                package,
+                reporter,
+                cache: project.get_script_run_cache(),
+            })
+        })
+        .unwrap_or_else(|err| ScriptRunResult::error(format!("{:#}", err)))
+}
+
+fn explain_package(
+    package: &Package,
+    project: &Project,
+    script_spec: &str,
+    script_args: &[String],
+    reporter: &impl ProgressReporter,
+) -> ScriptRunExplanation {
+    let candidates = match get_script_runner(package, project) {
+        Ok(script_runner) => script_runner.explain_script(&ScriptRunContext {
+            script_spec,
+            script_args,
+            package,
+            reporter,
+            cache: project.get_script_run_cache(),
+        }),
+        Err(err) => vec![ScriptRunnerCandidate {
+            runner_id: package.archetype_id.clone(),
+            can_run: false,
+            error: Some(format!("{:#}", err)),
+            selected: false,
+        }],
+    };
+
+    ScriptRunExplanation::new(candidates)
 }
 
 impl RunScriptArgs {
-    fn exec_interactive(&self, packages: &[Package]) -> Vec<PackageResult<ScriptRunResult>> {
+    fn exec_explain(
+        &self,
+        project: &Project,
+        packages: &[Package],
+        interactive: bool,
+    ) -> Vec<PackageResult<ScriptRunExplanation>> {
+        let explanations: Vec<_> = packages
+            .iter()
+            .map(|package| PackageResult {
+                package: package.clone(),
+                result: explain_package(
+                    package,
+                    project,
+                    &self.script_spec,
+                    &self.script_args,
+                    &NoopProgressReporter {},
+                ),
+            })
+            .collect();
+
+        if interactive {
+            let mut builder = Builder::default();
+            builder.push_record(["package", "runner", "can run", "selected"]);
+            for explanation in &explanations {
+                let selected = explanation.result.selected_runner_id.as_deref();
+                for candidate in &explanation.result.candidates {
+                    let can_run = match (&candidate.error, candidate.can_run) {
+                        (Some(error), _) => format!("error: {}", error),
+                        (None, true) => "yes".to_string(),
+                        (None, false) => "no".to_string(),
+                    };
+
+                    builder.push_record([
+                        explanation.package.name.as_str(),
+                        candidate.runner_id.as_str(),
+                        can_run.as_str(),
+                        if candidate.selected { "✔" } else { "" },
+                    ]);
+                }
+                if selected.is_none() {
+                    builder.push_record([explanation.package.name.as_str(), "-", "-", "skip"]);
+                }
+            }
+
+            let mut table = builder.build();
+            table.with(Style::blank());
+
+            println!("{}", table);
+        }
+
+        explanations
+    }
+
+    fn exec_interactive(
+        &self,
+        project: &Project,
+        packages: &[Package],
+    ) -> Vec<PackageResult<ScriptRunResult>> {
         let spinner_style = ProgressStyle::with_template("{prefix:.bold.dim} {spinner} {wide_msg}")
             .unwrap()
             .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ");
         let multi_progress = MultiProgress::new();
 
-        packages
-            .iter()
-            .map(|package| {
-                let package = package.clone();
-                let script_spec = self.script_spec.clone();
-
-                let progress_bar = multi_progress.add(ProgressBar::new_spinner());
-                progress_bar.set_style(spinner_style.clone());
-                progress_bar.set_prefix(format!("[{}]", package.name));
-                progress_bar.enable_steady_tick(Duration::from_millis(100));
-
-                thread::spawn(move || {
-                    let reporter = ProgressBarReporter {
-                        progress_bar: &progress_bar,
-                    };
+        // Scoped threads borrow the project, archetypes are shared between packages
+        thread::scope(|scope| {
+            packages
+                .iter()
+                .map(|package| {
+                    let progress_bar = multi_progress.add(ProgressBar::new_spinner());
+                    progress_bar.set_style(spinner_style.clone());
+                    progress_bar.set_prefix(format!("[{}]", package.name));
+                    progress_bar.enable_steady_tick(Duration::from_millis(100));
 
-                    let result = exec_package(&package, &script_spec, &reporter).unwrap();
+                    scope.spawn(move || {
+                        let reporter = ProgressBarReporter {
+                            progress_bar: &progress_bar,
+                        };
 
-                    match result.result_type {
-                        ScriptRunResultType::Success => {
-                            progress_bar.finish_with_message(format!(
-                                "{}: {} ✨",
-                                result.command,
-                                style("Done").green()
-                            ));
-                        }
-                        ScriptRunResultType::Error(ref message) => {
-                            progress_bar.finish_with_message(format!(
-                                "{}: {} ❌\n{}",
-                                result.command,
-                                style(message).red(),
-                                result.stderr
-                            ));
-                        }
-                        ScriptRunResultType::Noop => {
-                            progress_bar.finish_with_message("Skipped! ⏭️".to_string())
+                        let result = exec_package(
+                            package,
+                            project,
+                            &self.script_spec,
+                            &self.script_args,
+                            &reporter,
+                        );
+
+                        match result.result_type {
+                            ScriptRunResultType::Success => {
+                                progress_bar.finish_with_message(format!(
+                                    "{}: {} ✨",
+                                    result.command,
+                                    style("Done").green()
+                                ));
+                            }
+                            ScriptRunResultType::Error(ref message) => {
+                                progress_bar.finish_with_message(format!(
+                                    "{}: {} ❌\n{}",
+                                    result.command,
+                                    style(message).red(),
+                                    result.stderr
+                                ));
+                            }
+                            ScriptRunResultType::Noop => {
+                                progress_bar.finish_with_message("Skipped! ⏭️".to_string())
+                            }
                         }
-                    }
 
-                    PackageResult { package, result }
+                        PackageResult {
+                            package: package.clone(),
+                            result,
+                        }
+                    })
                 })
-            })
-            .collect::<Vec<_>>()
-            .into_iter()
-            .map(|h| h.join().unwrap())
-            .collect()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect()
+        })
     }
 
-    fn exec_non_interactive(&self, packages: &[Package]) -> Vec<PackageResult<ScriptRunResult>> {
-        packages
-            .iter()
-            .map(|package| {
-                let package = package.clone();
-                let script_spec = self.script_spec.clone();
-
-                thread::spawn(move || {
-                    let reporter = NoopProgressReporter {};
+    fn exec_non_interactive(
+        &self,
+        project: &Project,
+        packages: &[Package],
+    ) -> Vec<PackageResult<ScriptRunResult>> {
+        thread::scope(|scope| {
+            packages
+                .iter()
+                .map(|package| {
+                    scope.spawn(move || {
+                        let reporter = NoopProgressReporter {};
 
-                    let result = exec_package(&package, &script_spec, &reporter).unwrap();
+                        let result = exec_package(
+                            package,
+                            project,
+                            &self.script_spec,
+                            &self.script_args,
+                            &reporter,
+                        );
 
-                    PackageResult {
-                        package: package.clone(),
-                        result,
-                    }
+                        PackageResult {
+                            package: package.clone(),
+                            result,
+                        }
+                    })
                 })
-            })
-            .collect::<Vec<_>>()
-            .into_iter()
-            .map(|h| h.join().unwrap())
-            .collect()
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(|h| h.join().unwrap())
+                .collect()
+        })
     }
 }
 
@@ -139,13 +271,88 @@ impl CommandExec<RunScriptResult> for RunScriptArgs {
         &self,
         context: &impl super::CommandExecutionContext,
     ) -> Box<dyn CommandResult<RunScriptResult>> {
-        let packages = context.get_project().get_packages(false);
+        let project = context.get_project();
+        let packages = self.filter.apply(project.get_packages(false));
+
+        if self.explain {
+            let explanations =
+                self.exec_explain(&project, &packages, context.get_cli().is_interactive());
 
-        let results = match context.get_cli().is_interactive() {
-            true => self.exec_interactive(&packages),
-            false => self.exec_non_interactive(&packages),
+            return Box::from(RunScriptResult {
+                results: vec![],
+                explanations,
+            });
+        }
+
+        // Packages run after the packages they depend on
+        let graph = PackageGraph::new(packages);
+        let levels = match graph.get_levels(self.allow_cycles) {
+            Ok(levels) => levels,
+            Err(err) => {
+                eprintln!("{}. Use --allow-cycles to run them anyway", err);
+                std::process::exit(1);
+            }
         };
 
-        Box::from(RunScriptResult { results })
+        let interactive = context.get_cli().is_interactive();
+        let mut results = vec![];
+        // Packages that failed or were skipped, their dependents are skipped as well
+        let mut failed: Vec<usize> = vec![];
+        for level in levels {
+            let (runnable, skipped): (Vec<usize>, Vec<usize>) =
+                level.into_iter().partition(|&index| {
+                    !graph
+                        .get_dependencies(index)
+                        .iter()
+                        .any(|dependency| failed.contains(dependency))
+                });
+
+            for index in skipped {
+                let package = &graph.get_packages()[index];
+                let dependencies: Vec<&str> = graph
+                    .get_dependencies(index)
+                    .iter()
+                    .filter(|dependency| failed.contains(dependency))
+                    .map(|&dependency| graph.get_packages()[dependency].name.as_str())
+                    .collect();
+                let reason = format!("Dependency failed: {}", dependencies.join(", "));
+
+                if interactive {
+                    println!(
+                        "{} Skipped! ⏭️ {}",
+                        style(format!("[{}]", package.name)).bold().dim(),
+                        reason
+                    );
+                }
+
+                failed.push(index);
+                results.push(PackageResult {
+                    package: package.clone(),
+                    result: ScriptRunResult::skipped(reason),
+                });
+            }
+
+            let packages: Vec<Package> = runnable
+                .iter()
+                .map(|&index| graph.get_packages()[index].clone())
+                .collect();
+            let level_results = match interactive {
+                true => self.exec_interactive(&project, &packages),
+                false => self.exec_non_interactive(&project, &packages),
+            };
+
+            // Results are in the order of the packages
+            for (&index, result) in runnable.iter().zip(&level_results) {
+                if matches!(result.result.result_type, ScriptRunResultType::Error(_)) {
+                    failed.push(index);
+                }
+            }
+            results.extend(level_results);
+        }
+
+        Box::from(RunScriptResult {
+            results,
+            explanations: vec![],
+        })
     }
 }
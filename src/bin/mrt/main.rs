@@ -1,6 +1,9 @@
 use clap::{CommandFactory, Parser, Subcommand, ValueHint};
 use clap_complete::{Shell, generate};
-use commands::{CommandExec, CommandExecutionContext, list::ListArgs, run_script::RunScriptArgs};
+use commands::{
+    CommandExec, CommandExecutionContext, graph::GraphArgs, info::InfoArgs, list::ListArgs,
+    run_script::RunScriptArgs,
+};
 use mrt::project::Project;
 use output::write_command_stdout_as_json;
 use serde::ser;
@@ -36,8 +39,12 @@ enum Output {
 pub enum Commands {
     /// list detected packages
     List(ListArgs),
+    /// show details of a package
+    Info(InfoArgs),
     /// run scripts in all monorepo packages
     Run(RunScriptArgs),
+    /// render dependencies between packages
+    Graph(GraphArgs),
     /// outputs the completion file for given shell
     Completion {
         #[arg(index = 1, value_enum)]
@@ -81,9 +88,15 @@ fn main() {
         Some(Commands::List(args)) => {
             cli.exec_command(args);
         }
+        Some(Commands::Info(args)) => {
+            cli.exec_command(args);
+        }
         Some(Commands::Run(args)) => {
             cli.exec_command(args);
         }
+        Some(Commands::Graph(args)) => {
+            cli.exec_command(args);
+        }
         Some(Commands::Completion { shell }) => {
             let mut cmd = Cli::command();
             let name = cmd.get_name().to_string();
@@ -1,7 +1,14 @@
 use crate::{
-    Cli,
-    commands::{list::ListArgs, run_script::RunScriptArgs},
+    Cli, Output,
+    commands::{
+        CommandExec, PackageFilterArgs,
+        graph::{GraphArgs, GraphFormat},
+        info::InfoArgs,
+        list::ListArgs,
+        run_script::RunScriptArgs,
+    },
 };
+use mrt::runners::{ScriptRunResult, ScriptRunResultType};
 use std::{env, path::PathBuf};
 
 fn get_repo_root() -> PathBuf {
@@ -21,7 +28,364 @@ fn test_list_basic_sample() -> anyhow::Result<()> {
         output: None,
     };
 
-    cli.exec_command(&ListArgs { all: false });
+    cli.exec_command(&ListArgs {
+        all: false,
+        filter: PackageFilterArgs::default(),
+    });
+
+    Ok(())
+}
+
+#[test]
+fn test_filter_packages_by_tag_and_owner() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/ownership-sample/mrt.yml");
+    let project = mrt::project::Project::read(Some(manifest))?;
+
+    let filter = PackageFilterArgs {
+        tags: vec!["service".to_string()],
+        owners: vec![],
+        filters: vec![],
+    };
+    let mut names: Vec<String> = filter
+        .apply(project.get_packages(false))
+        .into_iter()
+        .map(|package| package.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["admin", "billing"]);
+
+    let filter = PackageFilterArgs {
+        tags: vec!["library".to_string()],
+        owners: vec!["@acme/platform".to_string()],
+        filters: vec![],
+    };
+    let names: Vec<String> = filter
+        .apply(project.get_packages(false))
+        .into_iter()
+        .map(|package| package.name)
+        .collect();
+    assert_eq!(names, vec!["ui-kit"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_filter_packages_by_selector() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/graph-sample/mrt.yml");
+    let project = mrt::project::Project::read(Some(manifest))?;
+
+    let filter = PackageFilterArgs {
+        tags: vec![],
+        owners: vec![],
+        filters: vec!["...^@acme/core".to_string(), "Acme_Utils".to_string()],
+    };
+    let names: Vec<String> = filter
+        .apply(project.get_packages(false))
+        .into_iter()
+        .map(|package| package.name)
+        .collect();
+    assert_eq!(names, vec!["Acme_Utils", "@acme/ui", "web"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_run_script_allow_cycles() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/graph-sample/mrt.yml");
+    let cli = Cli {
+        command: None,
+        manifest: Some(manifest),
+        output: None,
+    };
+
+    cli.exec_command(&RunScriptArgs {
+        script_spec: "build".to_string(),
+        script_args: vec![],
+        explain: false,
+        allow_cycles: true,
+        filter: PackageFilterArgs::default(),
+    });
+
+    Ok(())
+}
+
+#[test]
+fn test_run_script_skips_dependents_of_failed_package() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/graph-sample/mrt.yml");
+    let cli = Cli {
+        command: None,
+        manifest: Some(manifest),
+        output: Some(Output::Json),
+    };
+
+    // `check` of @acme/core fails, @acme/ui and web depend on it
+    let result = RunScriptArgs {
+        script_spec: "check".to_string(),
+        script_args: vec![],
+        explain: false,
+        allow_cycles: false,
+        filter: PackageFilterArgs {
+            tags: vec![],
+            owners: vec![],
+            filters: vec!["web...".to_string()],
+        },
+    }
+    .exec(&cli);
+    let results: Vec<(&str, &ScriptRunResult)> = result
+        .get_result()
+        .results
+        .iter()
+        .map(|result| (result.package.name.as_str(), &result.result))
+        .collect();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, "@acme/core");
+    assert!(matches!(
+        results[0].1.result_type,
+        ScriptRunResultType::Error(_)
+    ));
+    assert_eq!(results[1].0, "@acme/ui");
+    assert!(results[1].1.result_type.is_noop());
+    assert_eq!(
+        results[1].1.reason.as_deref(),
+        Some("Dependency failed: @acme/core")
+    );
+    assert_eq!(results[2].0, "web");
+    assert!(results[2].1.result_type.is_noop());
+    assert_eq!(
+        results[2].1.reason.as_deref(),
+        Some("Dependency failed: @acme/core, @acme/ui")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_run_script_reports_tool_that_cannot_start() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/missing-tool-sample/mrt.yml");
+    let cli = Cli {
+        command: None,
+        manifest: Some(manifest),
+        output: Some(Output::Json),
+    };
+
+    // Gradle wrapper of the package is not executable
+    let result = RunScriptArgs {
+        script_spec: "build".to_string(),
+        script_args: vec![],
+        explain: false,
+        allow_cycles: false,
+        filter: PackageFilterArgs::default(),
+    }
+    .exec(&cli);
+    let results = &result.get_result().results;
+
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        results[0].result.result_type,
+        ScriptRunResultType::Error(_)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_graph_sample() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/graph-sample/mrt.yml");
+    let graph = mrt::project::Project::read(Some(manifest))?.get_package_graph();
+    let args = GraphArgs {
+        package: None,
+        format: GraphFormat::Dot,
+        depth: None,
+        dependents: false,
+        dependencies: false,
+    };
+    let view = args.get_view(&graph).unwrap();
+
+    assert_eq!(
+        view.to_dot(),
+        r#"digraph packages {
+    "@acme/core";
+    "ping";
+    "pong";
+    "acme-api";
+    "Acme_Utils";
+    "@acme/ui";
+    "web";
+    "ping" -> "pong" [color=red];
+    "pong" -> "ping" [color=red];
+    "acme-api" -> "Acme_Utils";
+    "@acme/ui" -> "@acme/core";
+    "web" -> "@acme/core";
+    "web" -> "@acme/ui";
+}"#
+    );
+    assert_eq!(
+        view.to_mermaid(),
+        r#"graph LR
+    n0["@acme/core"]
+    n1["ping"]
+    n2["pong"]
+    n3["acme-api"]
+    n4["Acme_Utils"]
+    n5["@acme/ui"]
+    n6["web"]
+    n1 --> n2
+    n2 --> n1
+    n3 --> n4
+    n5 --> n0
+    n6 --> n0
+    n6 --> n5
+    linkStyle 0,1 stroke:red"#
+    );
+    assert_eq!(
+        serde_json::to_value(view.to_result())?,
+        serde_json::json!({
+            "dependencies": {
+                "@acme/core": [],
+                "@acme/ui": ["@acme/core"],
+                "Acme_Utils": [],
+                "acme-api": ["Acme_Utils"],
+                "ping": ["pong"],
+                "pong": ["ping"],
+                "web": ["@acme/core", "@acme/ui"],
+            },
+            "cycles": [["ping", "pong", "ping"]],
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_graph_sample_focused() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/graph-sample/mrt.yml");
+    let graph = mrt::project::Project::read(Some(manifest))?.get_package_graph();
+    let focus = |package: &str, depth, dependents, dependencies| GraphArgs {
+        package: Some(package.to_string()),
+        format: GraphFormat::Json,
+        depth,
+        dependents,
+        dependencies,
+    };
+
+    let view = focus("@acme/ui", Some(1), true, false)
+        .get_view(&graph)
+        .unwrap();
+    assert_eq!(
+        view.to_dot(),
+        "digraph packages {\n    \"@acme/ui\";\n    \"web\";\n    \"web\" -> \"@acme/ui\";\n}"
+    );
+
+    let view = focus("@acme/ui", None, false, true)
+        .get_view(&graph)
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(view.to_result())?,
+        serde_json::json!({"dependencies": {"@acme/core": [], "@acme/ui": ["@acme/core"]}})
+    );
+
+    // Both directions by default, depth counts steps away from the focused package
+    let view = focus("web", Some(1), false, false)
+        .get_view(&graph)
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(view.to_result())?,
+        serde_json::json!({"dependencies": {
+            "@acme/core": [],
+            "@acme/ui": ["@acme/core"],
+            "web": ["@acme/core", "@acme/ui"],
+        }})
+    );
+
+    // Cycles are only reported when they are part of the rendered graph
+    let view = focus("ping", None, false, false).get_view(&graph).unwrap();
+    assert_eq!(
+        serde_json::to_value(view.to_result())?,
+        serde_json::json!({
+            "dependencies": {"ping": ["pong"], "pong": ["ping"]},
+            "cycles": [["ping", "pong", "ping"]],
+        })
+    );
+
+    assert!(
+        focus("no-such-package", None, false, false)
+            .get_view(&graph)
+            .is_none()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_graph_quoting_and_cycle_links() -> anyhow::Result<()> {
+    let package = |name: &str, dependencies: &[&str]| {
+        serde_json::from_value::<mrt::package::Package>(serde_json::json!({
+            "name": name,
+            "version": "1.0.0",
+            "path": name,
+            "absolute_path": name,
+            "archetype_id": "nodejs/npm",
+            "metadata": {"dependencies": dependencies},
+            "status": "Valid",
+        }))
+    };
+    let graph = mrt::graph::PackageGraph::new(vec![
+        package("app", &["say \"hi\""])?,
+        package("say \"hi\"", &["loop"])?,
+        package("loop", &["say \"hi\""])?,
+    ]);
+    let args = GraphArgs {
+        package: None,
+        format: GraphFormat::Dot,
+        depth: None,
+        dependents: false,
+        dependencies: false,
+    };
+    let view = args.get_view(&graph).unwrap();
+
+    assert_eq!(
+        view.to_dot(),
+        r#"digraph packages {
+    "app";
+    "say \"hi\"";
+    "loop";
+    "app" -> "say \"hi\"";
+    "say \"hi\"" -> "loop" [color=red];
+    "loop" -> "say \"hi\"" [color=red];
+}"#
+    );
+    // Only the second and third links are part of the cycle
+    assert_eq!(
+        view.to_mermaid(),
+        r#"graph LR
+    n0["app"]
+    n1["say #quot;hi#quot;"]
+    n2["loop"]
+    n0 --> n1
+    n1 --> n2
+    n2 --> n1
+    linkStyle 1,2 stroke:red"#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_info_basic_sample() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/basic-sample/mrt.yml");
+    let cli = Cli {
+        command: None,
+        manifest: Some(manifest),
+        output: None,
+    };
+
+    cli.exec_command(&InfoArgs {
+        package: "node-lib1".to_string(),
+    });
+    cli.exec_command(&InfoArgs {
+        package: "./packages/py-lib2/".to_string(),
+    });
 
     Ok(())
 }
@@ -37,6 +401,10 @@ fn test_run_script_format_basic_sample() -> anyhow::Result<()> {
 
     cli.exec_command(&RunScriptArgs {
         script_spec: "format".to_string(),
+        script_args: vec![],
+        explain: false,
+        allow_cycles: false,
+        filter: PackageFilterArgs::default(),
     });
 
     Ok(())
@@ -53,6 +421,30 @@ fn test_run_script_build_basic_sample() -> anyhow::Result<()> {
 
     cli.exec_command(&RunScriptArgs {
         script_spec: "build".to_string(),
+        script_args: vec![],
+        explain: false,
+        allow_cycles: false,
+        filter: PackageFilterArgs::default(),
+    });
+
+    Ok(())
+}
+
+#[test]
+fn test_run_script_explain_basic_sample() -> anyhow::Result<()> {
+    let manifest = get_repo_root().join("./references/basic-sample/mrt.yml");
+    let cli = Cli {
+        command: None,
+        manifest: Some(manifest),
+        output: None,
+    };
+
+    cli.exec_command(&RunScriptArgs {
+        script_spec: "format".to_string(),
+        script_args: vec![],
+        explain: true,
+        allow_cycles: false,
+        filter: PackageFilterArgs::default(),
     });
 
     Ok(())
@@ -1,35 +1,234 @@
-use anyhow::Result;
-use std::path::Path;
+use anyhow::{Result, bail};
+use std::{
+    fmt::{Debug, Formatter},
+    path::Path,
+};
 
+use crate::cpp_cmake::CppCMakeArchetype;
+use crate::cpp_meson::CppMesonArchetype;
+use crate::deno_package::DenoPackageArchetype;
+use crate::dotnet_project::DotnetProjectArchetype;
+use crate::elixir_mix::ElixirMixArchetype;
+use crate::go_module::GoModuleArchetype;
+use crate::jvm_gradle::JvmGradleArchetype;
+use crate::jvm_maven::JvmMavenArchetype;
+use crate::manifest::RunnerPreferences;
 use crate::nodejs_npm::NodeJSNpmArchetype;
+use crate::php_composer::PhpComposerArchetype;
+use crate::python_pep621::PythonPep621Archetype;
 use crate::python_poetry::PythonPoetryArchetype;
-use crate::runners::ScriptRunner;
+use crate::ruby_bundler::RubyBundlerArchetype;
+use crate::runners::{ScriptRunner, WrapperScriptRunner};
 
-pub trait Archetype {
+/// Package type, e.g. npm package or Go module, that knows how to read package
+/// info and run scripts. Archetypes are shared between threads running scripts
+pub trait Archetype: Send + Sync {
     fn get_id(&self) -> &str;
     fn matcher(&self, package_path: &Path) -> bool;
-    fn get_script_runner(&self) -> Box<dyn ScriptRunner>;
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner>;
     fn get_info_extractor(
         &self,
         package_path: &Path,
     ) -> Result<Box<dyn crate::package::PackageInfoExtractor>>;
 }
 
-fn get_archetypes() -> Vec<Box<dyn Archetype>> {
-    vec![
-        Box::new(NodeJSNpmArchetype {}),
-        Box::new(PythonPoetryArchetype {}),
-    ]
+/// Ordered set of archetypes, the first archetype that matches a package directory wins
+pub struct ArchetypeRegistry {
+    archetypes: Vec<Box<dyn Archetype>>,
 }
 
-pub fn detect_archetype(package_path: &Path) -> Option<Box<dyn Archetype>> {
-    get_archetypes()
-        .into_iter()
-        .find(|archetype| archetype.matcher(package_path))
+impl Default for ArchetypeRegistry {
+    fn default() -> Self {
+        Self::builtin()
+    }
 }
 
-pub fn get_archetype_by_id(id: &str) -> Option<Box<dyn Archetype>> {
-    get_archetypes()
-        .into_iter()
-        .find(|archetype| archetype.get_id() == id)
+impl Debug for ArchetypeRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.get_ids()).finish()
+    }
+}
+
+impl ArchetypeRegistry {
+    /// Registry without any archetypes
+    pub fn new() -> Self {
+        Self { archetypes: vec![] }
+    }
+
+    /// Registry with archetypes shipped with MRT
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+
+        registry
+            .register(Box::new(NodeJSNpmArchetype {}))
+            .register(Box::new(DenoPackageArchetype {}))
+            .register(Box::new(PythonPoetryArchetype {}))
+            .register(Box::new(PythonPep621Archetype {}))
+            .register(Box::new(GoModuleArchetype {}))
+            .register(Box::new(JvmGradleArchetype {}))
+            .register(Box::new(JvmMavenArchetype {}))
+            .register(Box::new(DotnetProjectArchetype {}))
+            .register(Box::new(RubyBundlerArchetype {}))
+            .register(Box::new(PhpComposerArchetype {}))
+            .register(Box::new(ElixirMixArchetype {}))
+            .register(Box::new(CppCMakeArchetype {}))
+            .register(Box::new(CppMesonArchetype {}));
+
+        registry
+    }
+
+    /// Add archetype matched after all registered ones
+    pub fn register(&mut self, archetype: Box<dyn Archetype>) -> &mut Self {
+        self.archetypes.push(archetype);
+        self
+    }
+
+    /// Add archetype matched before all registered ones
+    pub fn register_first(&mut self, archetype: Box<dyn Archetype>) -> &mut Self {
+        self.archetypes.insert(0, archetype);
+        self
+    }
+
+    /// Add archetype matched right before the registered archetype with given id
+    pub fn register_before(&mut self, id: &str, archetype: Box<dyn Archetype>) -> Result<()> {
+        let Some(index) = self.archetypes.iter().position(|a| a.get_id() == id) else {
+            bail!("Archetype `{}` is not registered", id);
+        };
+
+        self.archetypes.insert(index, archetype);
+        Ok(())
+    }
+
+    /// Remove archetype with given id, e.g. to replace a built-in one
+    pub fn unregister(&mut self, id: &str) -> Option<Box<dyn Archetype>> {
+        let index = self.archetypes.iter().position(|a| a.get_id() == id)?;
+
+        Some(self.archetypes.remove(index))
+    }
+
+    pub fn get_ids(&self) -> Vec<&str> {
+        self.archetypes.iter().map(|a| a.get_id()).collect()
+    }
+
+    /// Ids of generic runners and of every runner used by registered archetypes, sorted
+    pub fn get_runner_ids(&self) -> Vec<String> {
+        let mut runner_ids: Vec<String> = self
+            .archetypes
+            .iter()
+            .map(|archetype| archetype.get_script_runner(&RunnerPreferences::default()))
+            .chain([Box::new(WrapperScriptRunner::generic_runners()) as Box<dyn ScriptRunner>])
+            .flat_map(|runner| {
+                runner
+                    .get_runner_ids()
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        runner_ids.sort();
+        runner_ids.dedup();
+        runner_ids
+    }
+
+    pub fn detect_archetype(&self, package_path: &Path) -> Option<&dyn Archetype> {
+        self.archetypes
+            .iter()
+            .find(|archetype| archetype.matcher(package_path))
+            .map(Box::as_ref)
+    }
+
+    /// Every archetype that matches the package directory, in registry order
+    pub fn detect_archetypes(&self, package_path: &Path) -> Vec<&dyn Archetype> {
+        self.archetypes
+            .iter()
+            .filter(|archetype| archetype.matcher(package_path))
+            .map(Box::as_ref)
+            .collect()
+    }
+
+    pub fn get_archetype_by_id(&self, id: &str) -> Option<&dyn Archetype> {
+        self.archetypes
+            .iter()
+            .find(|archetype| archetype.get_id() == id)
+            .map(Box::as_ref)
+    }
+}
+
+#[cfg(test)]
+struct TestArchetype {
+    id: &'static str,
+    file_name: &'static str,
+}
+
+#[cfg(test)]
+impl Archetype for TestArchetype {
+    fn get_id(&self) -> &str {
+        self.id
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join(self.file_name).exists()
+    }
+
+    fn get_script_runner(&self, _preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::new(crate::runners::NoopScriptRunner {})
+    }
+
+    fn get_info_extractor(
+        &self,
+        _package_path: &Path,
+    ) -> Result<Box<dyn crate::package::PackageInfoExtractor>> {
+        Ok(Box::new(crate::package::NoopPackageInfoExtractor {}))
+    }
+}
+
+#[test]
+fn test_registry_order() -> anyhow::Result<()> {
+    let lib1_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/packages/node-lib1");
+    let mut registry = ArchetypeRegistry::builtin();
+
+    assert_eq!(
+        registry.detect_archetype(&lib1_path).map(|a| a.get_id()),
+        Some("nodejs/npm")
+    );
+
+    registry.register(Box::new(TestArchetype {
+        id: "test/last",
+        file_name: "package.json",
+    }));
+    assert_eq!(
+        registry.detect_archetype(&lib1_path).map(|a| a.get_id()),
+        Some("nodejs/npm")
+    );
+
+    registry.register_before(
+        "nodejs/npm",
+        Box::new(TestArchetype {
+            id: "test/before-npm",
+            file_name: "package.json",
+        }),
+    )?;
+    assert_eq!(
+        registry.detect_archetype(&lib1_path).map(|a| a.get_id()),
+        Some("test/before-npm")
+    );
+    assert!(registry.get_archetype_by_id("test/last").is_some());
+
+    assert!(registry.unregister("test/before-npm").is_some());
+    assert_eq!(
+        registry.detect_archetype(&lib1_path).map(|a| a.get_id()),
+        Some("nodejs/npm")
+    );
+
+    let result = registry.register_before(
+        "no/such-archetype",
+        Box::new(TestArchetype {
+            id: "test/orphan",
+            file_name: "package.json",
+        }),
+    );
+    assert!(result.is_err());
+
+    Ok(())
 }
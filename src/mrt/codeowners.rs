@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use glob::{MatchOptions, Pattern};
+use std::path::Path;
+
+/// Locations GitHub looks for the file, in order
+const CODEOWNERS_PATHS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Ownership rules from a `CODEOWNERS` file, the last matching rule wins
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<CodeOwnersRule>,
+}
+
+#[derive(Debug, Clone)]
+struct CodeOwnersRule {
+    pattern: Pattern,
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// Read the first `CODEOWNERS` file of the repository, if any
+    pub fn find(root_path: &Path) -> Result<Option<CodeOwners>> {
+        let Some(path) = CODEOWNERS_PATHS
+            .iter()
+            .map(|path| root_path.join(path))
+            .find(|path| path.is_file())
+        else {
+            return Ok(None);
+        };
+
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+
+        Ok(Some(CodeOwners::parse(&content)))
+    }
+
+    /// Lines that cannot be parsed are skipped, same as GitHub does
+    pub fn parse(content: &str) -> CodeOwners {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line
+                    .split_whitespace()
+                    .take_while(|part| !part.starts_with('#'));
+                let pattern = to_glob(parts.next()?)?;
+
+                Some(CodeOwnersRule {
+                    pattern,
+                    owners: parts.map(str::to_string).collect(),
+                })
+            })
+            .collect();
+
+        CodeOwners { rules }
+    }
+
+    /// Owners of a package directory, relative to the repository root.
+    ///
+    /// A rule owns the package when it matches the package directory or one of its
+    /// parents, rules for files inside the package do not.
+    pub fn get_owners(&self, package_path: &str) -> Vec<String> {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::new()
+        };
+
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| {
+                Path::new(package_path)
+                    .ancestors()
+                    .filter(|path| !path.as_os_str().is_empty())
+                    .any(|path| rule.pattern.matches_path_with(path, options))
+            })
+            .map(|rule| rule.owners.clone())
+            .unwrap_or_default()
+    }
+}
+
+/// Convert gitignore-style pattern to a glob relative to the repository root
+fn to_glob(pattern: &str) -> Option<Pattern> {
+    let anchored = pattern.starts_with('/');
+    let pattern = pattern.trim_start_matches('/');
+    let pattern = pattern.strip_suffix("/**").unwrap_or(pattern);
+    let pattern = pattern.trim_end_matches('/');
+
+    // Patterns without a slash match at any depth
+    let pattern = if !anchored && !pattern.contains('/') {
+        format!("**/{}", pattern)
+    } else {
+        pattern.to_string()
+    };
+
+    Pattern::new(&pattern).ok()
+}
+
+#[test]
+fn test_get_owners() {
+    let codeowners = CodeOwners::parse(
+        r#"
+# Default owners
+*                   @acme/core
+
+/packages/          @acme/platform
+/packages/billing/  @acme/payments @jane   # billing team
+apps/*              @acme/frontend
+docs                @acme/docs
+/packages/web/src/  @acme/design
+/packages/legacy
+"#,
+    );
+
+    assert_eq!(
+        codeowners.get_owners("packages/billing"),
+        vec!["@acme/payments", "@jane"]
+    );
+    assert_eq!(
+        codeowners.get_owners("packages/web"),
+        vec!["@acme/platform"]
+    );
+    assert_eq!(codeowners.get_owners("apps/admin"), vec!["@acme/frontend"]);
+    assert_eq!(codeowners.get_owners("tools/docs"), vec!["@acme/docs"]);
+    assert_eq!(codeowners.get_owners("tools/cli"), vec!["@acme/core"]);
+    assert!(codeowners.get_owners("packages/legacy").is_empty());
+}
@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::package::PackageInfoExtractor;
+
+pub struct CMakePackageInfoExtractor {
+    name: String,
+    version: String,
+}
+
+impl CMakePackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<CMakePackageInfoExtractor> {
+        let cmake_lists_path = package_path.join("CMakeLists.txt");
+
+        let content = std::fs::read_to_string(&cmake_lists_path).with_context(|| {
+            format!(
+                "Failed to read CMakeLists.txt file at {}",
+                cmake_lists_path.display()
+            )
+        })?;
+
+        let arguments = parse_project_arguments(&content).with_context(|| {
+            format!(
+                "Failed to find project() command in CMakeLists.txt file at {}",
+                cmake_lists_path.display()
+            )
+        })?;
+
+        let name = arguments
+            .first()
+            .cloned()
+            .unwrap_or_else(|| String::from("n/a"));
+
+        let version = arguments
+            .iter()
+            .skip_while(|argument| *argument != "VERSION")
+            .nth(1)
+            .cloned()
+            .unwrap_or_else(|| String::from("n/a"));
+
+        Ok(CMakePackageInfoExtractor { name, version })
+    }
+}
+
+/// Arguments of `project(...)` command, which may span several lines
+fn parse_project_arguments(content: &str) -> Option<Vec<String>> {
+    // Drop comments first, they may contain parentheses
+    let content: String = content
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Same byte offsets as the content, unlike `to_lowercase` for non-ASCII text
+    let lowercase = content.to_ascii_lowercase();
+    let start = lowercase
+        .match_indices("project")
+        .map(|(index, _)| index)
+        .find(|index| {
+            // Skip commands like `cmake_project_...` or variables like `${PROJECT_NAME}`
+            let is_command_start = content[..*index]
+                .chars()
+                .next_back()
+                .is_none_or(char::is_whitespace);
+            is_command_start
+                && content[index + "project".len()..]
+                    .trim_start()
+                    .starts_with('(')
+        })?;
+
+    let arguments = &content[start..];
+    let arguments = &arguments[arguments.find('(')? + 1..];
+    let arguments = &arguments[..arguments.find(')')?];
+
+    Some(
+        arguments
+            .split_whitespace()
+            .map(|argument| argument.trim_matches('"').to_string())
+            .collect(),
+    )
+}
+
+impl PackageInfoExtractor for CMakePackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = CMakePackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_success() {
+    let addon_path =
+        crate::testing::utils::get_repo_root().join("./references/cpp-sample/native/addon");
+    let result = CMakePackageInfoExtractor::from_package_path(&addon_path).unwrap();
+    assert_eq!(result.get_name(), "NativeAddon");
+    assert_eq!(result.get_version(), "1.2.0");
+}
+
+#[test]
+fn test_parse_project_arguments_single_line() {
+    let content = "cmake_minimum_required(VERSION 3.10)\nproject(\"tool\" LANGUAGES C)\n";
+    assert_eq!(
+        parse_project_arguments(content),
+        Some(vec![
+            "tool".to_string(),
+            "LANGUAGES".to_string(),
+            "C".to_string()
+        ])
+    );
+}
+
+#[test]
+fn test_parse_project_arguments_non_ascii() {
+    let content = "set(CITY \"İstanbul\")\nproject(cm VERSION 2.0.0)\n";
+    assert_eq!(
+        parse_project_arguments(content),
+        Some(vec![
+            "cm".to_string(),
+            "VERSION".to_string(),
+            "2.0.0".to_string()
+        ])
+    );
+}
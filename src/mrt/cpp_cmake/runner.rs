@@ -0,0 +1,126 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+const BUILD_DIR: &str = "build";
+
+/// Maps script names to CMake and CTest, configuring the build directory on first use
+pub struct CMakeScriptRunner {
+    cmake_runner: CommandRunner,
+    ctest_runner: CommandRunner,
+}
+
+impl CMakeScriptRunner {
+    pub fn new() -> CMakeScriptRunner {
+        CMakeScriptRunner {
+            cmake_runner: CommandRunner::new("cmake".to_string()),
+            ctest_runner: CommandRunner::new("ctest".to_string()),
+        }
+    }
+
+    /// Commands of the script, the build directory is configured on first use. CTest and
+    /// `cmake --install` do not build outdated targets, so `test` and `install` build first
+    fn get_steps(
+        &self,
+        script_spec: &str,
+        package_path: &Path,
+    ) -> Option<Vec<(&CommandRunner, Vec<String>)>> {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect();
+        let build = (&self.cmake_runner, args(&["--build", BUILD_DIR]));
+
+        let command = match script_spec {
+            "build" => None,
+            "test" => Some((&self.ctest_runner, args(&["--test-dir", BUILD_DIR]))),
+            "install" => Some((&self.cmake_runner, args(&["--install", BUILD_DIR]))),
+            _ => return None,
+        };
+
+        let mut steps = vec![];
+        if !package_path
+            .join(BUILD_DIR)
+            .join("CMakeCache.txt")
+            .is_file()
+        {
+            steps.push((&self.cmake_runner, args(&["-S", ".", "-B", BUILD_DIR])));
+        }
+        steps.push(build);
+        steps.extend(command);
+        Some(steps)
+    }
+}
+
+impl ScriptRunner for CMakeScriptRunner {
+    fn get_id(&self) -> &str {
+        "cmake"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let mut steps = self
+            .get_steps(context.script_spec, &context.package.absolute_path)
+            .unwrap_or_default();
+
+        // Script arguments are for the requested command, not for the steps before it
+        let Some((runner, mut args)) = steps.pop() else {
+            return Ok(ScriptRunResult::noop());
+        };
+        for (step_runner, step_args) in steps {
+            let result = step_runner.run_script(step_args, context)?;
+            if !result.result_type.is_success() {
+                return Ok(result);
+            }
+        }
+
+        args.extend(context.script_args.iter().cloned());
+
+        runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(self
+            .get_steps(context.script_spec, &context.package.absolute_path)
+            .is_some())
+    }
+}
+
+#[test]
+fn test_get_steps() {
+    let runner = CMakeScriptRunner::new();
+    let addon_path =
+        crate::testing::utils::get_repo_root().join("./references/cpp-sample/native/addon");
+
+    let steps = |script_spec: &str| {
+        runner.get_steps(script_spec, &addon_path).map(|steps| {
+            steps
+                .iter()
+                .map(|(runner, args)| format!("{} {}", runner.program, args.join(" ")))
+                .collect::<Vec<_>>()
+        })
+    };
+
+    // Sample has no configured build directory, so every script configures it first
+    assert_eq!(
+        steps("build"),
+        Some(vec![
+            "cmake -S . -B build".to_string(),
+            "cmake --build build".to_string()
+        ])
+    );
+    assert_eq!(
+        steps("test"),
+        Some(vec![
+            "cmake -S . -B build".to_string(),
+            "cmake --build build".to_string(),
+            "ctest --test-dir build".to_string()
+        ])
+    );
+    assert_eq!(
+        steps("install"),
+        Some(vec![
+            "cmake -S . -B build".to_string(),
+            "cmake --build build".to_string(),
+            "cmake --install build".to_string()
+        ])
+    );
+    assert_eq!(steps("format"), None);
+}
@@ -0,0 +1,109 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::package::PackageInfoExtractor;
+
+pub struct MesonPackageInfoExtractor {
+    name: String,
+    version: String,
+}
+
+impl MesonPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<MesonPackageInfoExtractor> {
+        let meson_build_path = package_path.join("meson.build");
+
+        let content = std::fs::read_to_string(&meson_build_path).with_context(|| {
+            format!(
+                "Failed to read meson.build file at {}",
+                meson_build_path.display()
+            )
+        })?;
+
+        // `project('name', 'cpp', version : '1.0.0', ...)` is the first statement
+        let arguments = content
+            .find("project(")
+            .map(|start| &content[start + "project(".len()..])
+            .with_context(|| {
+                format!(
+                    "Failed to find project() in meson.build file at {}",
+                    meson_build_path.display()
+                )
+            })?;
+
+        let name = parse_string_literal(arguments).unwrap_or_else(|| String::from("n/a"));
+
+        let version =
+            parse_keyword_argument(arguments, "version").unwrap_or_else(|| String::from("n/a"));
+
+        Ok(MesonPackageInfoExtractor { name, version })
+    }
+}
+
+/// String value of `keyword : 'value'` argument, `meson_version` is not `version`
+fn parse_keyword_argument(arguments: &str, keyword: &str) -> Option<String> {
+    arguments.match_indices(keyword).find_map(|(index, _)| {
+        // Arguments start right after `project(`
+        let is_keyword_start = arguments[..index]
+            .chars()
+            .next_back()
+            .is_none_or(|previous| previous == ',' || previous == '(' || previous.is_whitespace());
+        if !is_keyword_start {
+            return None;
+        }
+
+        let value = arguments[index + keyword.len()..]
+            .trim_start()
+            .strip_prefix(':')?;
+        parse_string_literal(value)
+    })
+}
+
+/// First single quoted Meson string in the value
+fn parse_string_literal(value: &str) -> Option<String> {
+    let value = value.trim_start().strip_prefix('\'')?;
+
+    Some(value[..value.find('\'')?].to_string())
+}
+
+impl PackageInfoExtractor for MesonPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = MesonPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_success() {
+    let codec_path =
+        crate::testing::utils::get_repo_root().join("./references/cpp-sample/native/codec");
+    let result = MesonPackageInfoExtractor::from_package_path(&codec_path).unwrap();
+    assert_eq!(result.get_name(), "codec");
+    assert_eq!(result.get_version(), "0.4.0");
+}
+
+#[test]
+fn test_parse_keyword_argument() {
+    let arguments = "'ms', 'c', meson_version : '>=0.60', version : '1.2.3')";
+    assert_eq!(
+        parse_keyword_argument(arguments, "version").as_deref(),
+        Some("1.2.3")
+    );
+    assert_eq!(
+        parse_keyword_argument("version: '2.0')", "version").as_deref(),
+        Some("2.0")
+    );
+    assert_eq!(
+        parse_keyword_argument("'ms', meson_version : '>=0.60')", "version"),
+        None
+    );
+}
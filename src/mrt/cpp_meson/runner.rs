@@ -0,0 +1,57 @@
+use anyhow::Result;
+
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+const BUILD_DIR: &str = "build";
+
+/// Maps script names to Meson commands, setting up the build directory on first use
+pub struct MesonScriptRunner {
+    meson_runner: CommandRunner,
+}
+
+impl MesonScriptRunner {
+    pub fn new() -> MesonScriptRunner {
+        MesonScriptRunner {
+            meson_runner: CommandRunner::new("meson".to_string()),
+        }
+    }
+
+    fn get_command(script_spec: &str) -> Option<&'static str> {
+        match script_spec {
+            "build" => Some("compile"),
+            "test" => Some("test"),
+            "install" => Some("install"),
+            _ => None,
+        }
+    }
+}
+
+impl ScriptRunner for MesonScriptRunner {
+    fn get_id(&self) -> &str {
+        "meson"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let Some(command) = Self::get_command(context.script_spec) else {
+            return Ok(ScriptRunResult::noop());
+        };
+
+        let build_path = context.package.absolute_path.join(BUILD_DIR);
+        if !build_path.join("meson-private").is_dir() {
+            let setup_args = vec!["setup".to_string(), BUILD_DIR.to_string()];
+            let result = self.meson_runner.run_script(setup_args, context)?;
+            if !result.result_type.is_success() {
+                return Ok(result);
+            }
+        }
+
+        let mut args = vec![command.to_string(), "-C".to_string(), BUILD_DIR.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        self.meson_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(Self::get_command(context.script_spec).is_some())
+    }
+}
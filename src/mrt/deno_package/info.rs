@@ -0,0 +1,164 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use crate::package::PackageInfoExtractor;
+
+const DENO_CONFIG_FILE_NAMES: [&str; 2] = ["deno.json", "deno.jsonc"];
+
+pub struct DenoPackageInfoExtractor {
+    deno_config: DenoConfig,
+}
+
+// Structure represents deno.json info, name and version are only required for JSR packages
+#[derive(Deserialize, Debug)]
+struct DenoConfig {
+    name: Option<String>,
+    version: Option<String>,
+    #[serde(default)]
+    tasks: BTreeMap<String, serde_json::Value>,
+}
+
+pub(crate) fn find_deno_config(package_path: &Path) -> Option<PathBuf> {
+    DENO_CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| package_path.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Strip comments and trailing commas, so JSONC can be parsed as JSON
+fn strip_jsonc(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            result.push(c);
+            match c {
+                '\\' => result.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                result.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|c| *c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            (']' | '}', _) => {
+                // Drop trailing comma before closing bracket
+                let trimmed_len = result.trim_end().len();
+                if result[..trimmed_len].ends_with(',') {
+                    result.remove(trimmed_len - 1);
+                }
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+    }
+
+    result
+}
+
+impl DenoPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<DenoPackageInfoExtractor> {
+        let deno_config_path = find_deno_config(package_path).with_context(|| {
+            format!(
+                "Failed to find deno.json or deno.jsonc file at {}",
+                package_path.display()
+            )
+        })?;
+
+        let content = std::fs::read_to_string(&deno_config_path).with_context(|| {
+            format!(
+                "Failed to read Deno config file at {}",
+                deno_config_path.display()
+            )
+        })?;
+
+        let deno_config = serde_json::from_str(&strip_jsonc(&content)).with_context(|| {
+            format!(
+                "Failed to parse JSON of Deno config file at {}",
+                deno_config_path.display()
+            )
+        })?;
+
+        Ok(DenoPackageInfoExtractor { deno_config })
+    }
+
+    pub fn has_task(&self, task_name: &str) -> bool {
+        self.deno_config.tasks.contains_key(task_name)
+    }
+}
+
+impl PackageInfoExtractor for DenoPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.deno_config.name.as_deref().unwrap_or("n/a")
+    }
+
+    fn get_version(&self) -> &str {
+        self.deno_config.version.as_deref().unwrap_or("n/a")
+    }
+
+    fn get_scripts(&self) -> Vec<String> {
+        self.deno_config.tasks.keys().cloned().collect()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = DenoPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_jsonc() {
+    let lib_path =
+        crate::testing::utils::get_repo_root().join("./references/deno-sample/packages/deno-lib");
+    let result = DenoPackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "@acme/deno-lib");
+    assert_eq!(result.get_version(), "0.3.0");
+    assert!(result.has_task("test"));
+    assert!(!result.has_task("format"));
+}
+
+#[test]
+fn test_from_package_path_without_name() {
+    let tool_path =
+        crate::testing::utils::get_repo_root().join("./references/deno-sample/packages/deno-tool");
+    let result = DenoPackageInfoExtractor::from_package_path(&tool_path).unwrap();
+    assert_eq!(result.get_name(), "n/a");
+    assert!(result.has_task("format"));
+}
+
+#[test]
+fn test_strip_jsonc() {
+    let content = r#"{
+  // line comment
+  "url": "https://jsr.io", /* block */
+  "quote": "say \"hi\" // not a comment",
+  "list": [1, 2,],
+}"#;
+    let value: serde_json::Value = serde_json::from_str(&strip_jsonc(content)).unwrap();
+    assert_eq!(value["url"], "https://jsr.io");
+    assert_eq!(value["quote"], "say \"hi\" // not a comment");
+    assert_eq!(value["list"], serde_json::json!([1, 2]));
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{
+    info::{DenoPackageInfoExtractor, find_deno_config},
+    runner::DenoTaskScriptRunner,
+};
+
+mod info;
+mod runner;
+
+pub struct DenoPackageArchetype {}
+
+impl Archetype for DenoPackageArchetype {
+    fn get_id(&self) -> &str {
+        "deno/package"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        // Deno packages with package.json are run by Node package managers
+        find_deno_config(package_path).is_some() && !package_path.join("package.json").exists()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(DenoTaskScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            DenoPackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
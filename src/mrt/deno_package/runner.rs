@@ -0,0 +1,37 @@
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+use anyhow::Result;
+
+use super::info::DenoPackageInfoExtractor;
+
+/// Runs `tasks` of deno.json with `deno task`
+pub struct DenoTaskScriptRunner {
+    deno_runner: CommandRunner,
+}
+
+impl DenoTaskScriptRunner {
+    pub fn new() -> DenoTaskScriptRunner {
+        DenoTaskScriptRunner {
+            deno_runner: CommandRunner::new("deno".to_string()),
+        }
+    }
+}
+
+impl ScriptRunner for DenoTaskScriptRunner {
+    fn get_id(&self) -> &str {
+        "deno"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        // `deno task` forwards everything after the task name
+        let mut args = vec!["task".to_string(), context.script_spec.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        self.deno_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        let info = DenoPackageInfoExtractor::from_package_path(&context.package.absolute_path)?;
+
+        Ok(info.has_task(context.script_spec))
+    }
+}
@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use roxmltree::Document;
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::package::{PackageInfoExtractor, find_in_ancestors};
+
+const PROJECT_FILE_EXTENSIONS: [&str; 2] = ["csproj", "fsproj"];
+
+pub struct DotnetPackageInfoExtractor {
+    name: String,
+    version: String,
+}
+
+/// First C# or F# project file in the package directory, in name order
+pub(crate) fn find_project_file(package_path: &Path) -> Option<PathBuf> {
+    let mut project_files: Vec<PathBuf> = std::fs::read_dir(package_path)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.is_file()
+                && path.extension().is_some_and(|extension| {
+                    PROJECT_FILE_EXTENSIONS.iter().any(|e| extension == *e)
+                })
+        })
+        .collect();
+
+    project_files.sort();
+    project_files.into_iter().next()
+}
+
+/// Collect `<PropertyGroup>` properties of MSBuild file, later values override earlier ones
+fn read_properties(path: &Path, properties: &mut HashMap<String, String>) -> Result<()> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read MSBuild file at {}", path.display()))?;
+
+    let document = Document::parse(&content)
+        .with_context(|| format!("Failed to parse XML of MSBuild file at {}", path.display()))?;
+
+    document
+        .root_element()
+        .children()
+        .filter(|node| node.tag_name().name() == "PropertyGroup")
+        .flat_map(|group| group.children().filter(|node| node.is_element()))
+        .for_each(|property| {
+            let value = expand_properties(property.text().unwrap_or_default().trim(), properties);
+            properties.insert(property.tag_name().name().to_string(), value);
+        });
+
+    Ok(())
+}
+
+/// Replace `$(Property)` references with values known so far
+fn expand_properties(value: &str, properties: &HashMap<String, String>) -> String {
+    let mut expanded = value.to_string();
+
+    for (key, property_value) in properties {
+        expanded = expanded.replace(&format!("$({})", key), property_value);
+    }
+
+    expanded
+}
+
+impl DotnetPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<DotnetPackageInfoExtractor> {
+        let project_file_path = find_project_file(package_path).with_context(|| {
+            format!(
+                "Failed to find .csproj or .fsproj file at {}",
+                package_path.display()
+            )
+        })?;
+
+        let mut properties = HashMap::new();
+
+        // MSBuild imports the nearest Directory.Build.props before the project itself
+        if let Some(props_path) = find_in_ancestors(package_path, "Directory.Build.props") {
+            read_properties(&props_path, &mut properties)?;
+        }
+        read_properties(&project_file_path, &mut properties)?;
+
+        let property = |name: &str| properties.get(name).filter(|value| !value.is_empty());
+
+        let name = property("PackageId")
+            .or_else(|| property("AssemblyName"))
+            .cloned()
+            .or_else(|| {
+                project_file_path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| String::from("n/a"));
+
+        // Same defaults as .NET SDK: Version is VersionPrefix with optional VersionSuffix
+        let version = match (property("Version"), property("VersionSuffix")) {
+            (Some(version), _) => version.clone(),
+            (None, suffix) => {
+                let prefix = property("VersionPrefix").map_or("1.0.0", String::as_str);
+                match suffix {
+                    Some(suffix) => format!("{}-{}", prefix, suffix),
+                    None => prefix.to_string(),
+                }
+            }
+        };
+
+        Ok(DotnetPackageInfoExtractor { name, version })
+    }
+}
+
+impl PackageInfoExtractor for DotnetPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = DotnetPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_package_id() {
+    let billing_path = crate::testing::utils::get_repo_root()
+        .join("./references/dotnet-sample/services/Billing.Api");
+    let result = DotnetPackageInfoExtractor::from_package_path(&billing_path).unwrap();
+    assert_eq!(result.get_name(), "Acme.Billing.Api");
+    assert_eq!(result.get_version(), "3.1.0-beta");
+}
+
+#[test]
+fn test_from_package_path_inherited_version() {
+    let reports_path =
+        crate::testing::utils::get_repo_root().join("./references/dotnet-sample/services/Reports");
+    let result = DotnetPackageInfoExtractor::from_package_path(&reports_path).unwrap();
+    assert_eq!(result.get_name(), "Reports");
+    assert_eq!(result.get_version(), "3.1.0-rc.1");
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{
+    info::{DotnetPackageInfoExtractor, find_project_file},
+    runner::DotnetScriptRunner,
+};
+
+mod info;
+mod runner;
+
+pub struct DotnetProjectArchetype {}
+
+impl Archetype for DotnetProjectArchetype {
+    fn get_id(&self) -> &str {
+        "dotnet/project"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        find_project_file(package_path).is_some()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(DotnetScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            DotnetPackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
+
+#[test]
+fn test_matcher() {
+    let services_path =
+        crate::testing::utils::get_repo_root().join("./references/dotnet-sample/services");
+    let archetype = DotnetProjectArchetype {};
+
+    assert!(archetype.matcher(&services_path.join("Billing.Api")));
+    // F# projects are matched by `.fsproj`
+    assert!(archetype.matcher(&services_path.join("Reports")));
+    assert!(!archetype.matcher(&services_path));
+}
+
+#[test]
+fn test_script_runner_explain() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/dotnet-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./services/Reports"))?;
+
+    assert_eq!(package.archetype_id, "dotnet/project");
+
+    let archetype = DotnetProjectArchetype {};
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
+
+    let explain = |script_spec: &str| {
+        let context = crate::runners::ScriptRunContext {
+            script_spec,
+            script_args: &[],
+            package: &package,
+            reporter: &crate::progress::LogProgressReporter {},
+            cache: &crate::runners::ScriptRunCache::default(),
+        };
+        crate::runners::ScriptRunExplanation::new(runner.explain_script(&context))
+            .selected_runner_id
+    };
+
+    assert_eq!(explain("build").as_deref(), Some("dotnet"));
+    assert_eq!(explain("test").as_deref(), Some("dotnet"));
+    assert_eq!(explain("format").as_deref(), Some("dotnet"));
+    assert_eq!(explain("lint"), None);
+
+    Ok(())
+}
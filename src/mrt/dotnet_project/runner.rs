@@ -0,0 +1,62 @@
+use anyhow::Result;
+
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+use super::info::find_project_file;
+
+/// Maps script names to `dotnet` CLI verbs
+pub struct DotnetScriptRunner {
+    dotnet_runner: CommandRunner,
+}
+
+impl DotnetScriptRunner {
+    pub fn new() -> DotnetScriptRunner {
+        DotnetScriptRunner {
+            dotnet_runner: CommandRunner::new("dotnet".to_string()),
+        }
+    }
+
+    fn get_verb(script_spec: &str) -> Option<&'static str> {
+        match script_spec {
+            "build" => Some("build"),
+            "test" => Some("test"),
+            "format" => Some("format"),
+            "clean" => Some("clean"),
+            _ => None,
+        }
+    }
+}
+
+impl ScriptRunner for DotnetScriptRunner {
+    fn get_id(&self) -> &str {
+        "dotnet"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let Some(verb) = Self::get_verb(context.script_spec) else {
+            return Ok(ScriptRunResult::noop());
+        };
+
+        let mut args = vec![verb.to_string()];
+        // dotnet refuses to guess when a directory has several project files
+        if let Some(project_file) = find_project_file(&context.package.absolute_path) {
+            args.push(project_file.to_string_lossy().to_string());
+        }
+        args.extend(context.script_args.iter().cloned());
+
+        self.dotnet_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(Self::get_verb(context.script_spec).is_some())
+    }
+}
+
+#[test]
+fn test_get_verb() {
+    assert_eq!(DotnetScriptRunner::get_verb("build"), Some("build"));
+    assert_eq!(DotnetScriptRunner::get_verb("test"), Some("test"));
+    assert_eq!(DotnetScriptRunner::get_verb("format"), Some("format"));
+    assert_eq!(DotnetScriptRunner::get_verb("clean"), Some("clean"));
+    assert_eq!(DotnetScriptRunner::get_verb("lint"), None);
+}
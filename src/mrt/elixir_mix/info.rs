@@ -0,0 +1,174 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::package::PackageInfoExtractor;
+
+pub struct MixPackageInfoExtractor {
+    name: String,
+    version: String,
+    aliases: Vec<String>,
+}
+
+impl MixPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<MixPackageInfoExtractor> {
+        let mix_exs_path = package_path.join("mix.exs");
+
+        let content = std::fs::read_to_string(&mix_exs_path).with_context(|| {
+            format!("Failed to read mix.exs file at {}", mix_exs_path.display())
+        })?;
+
+        // Mix uses the directory name when `app` is missing
+        let name = find_keyword(&content, "app")
+            .map(|app| app.trim_start_matches(':').to_string())
+            .or_else(|| {
+                package_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| String::from("n/a"));
+
+        // Version is often a module attribute, e.g. `@version "1.0.0"`
+        let version = find_keyword(&content, "version")
+            .and_then(|version| match version.strip_prefix('@') {
+                Some(attribute) => find_attribute(&content, attribute),
+                None => parse_string_literal(&version),
+            })
+            .unwrap_or_else(|| String::from("n/a"));
+
+        Ok(MixPackageInfoExtractor {
+            name,
+            version,
+            aliases: parse_aliases(&content),
+        })
+    }
+
+    pub fn has_alias(&self, alias: &str) -> bool {
+        self.aliases.iter().any(|name| name == alias)
+    }
+}
+
+/// Value of `key: value` keyword, up to the end of line or the next comma
+fn find_keyword(content: &str, key: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix(key)?.strip_prefix(':')?;
+        let value = value.split(',').next().unwrap_or(value).trim();
+
+        Some(value.to_string()).filter(|value| !value.is_empty())
+    })
+}
+
+/// String value of `@attribute "value"` module attribute
+fn find_attribute(content: &str, attribute: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let value = line.trim().strip_prefix('@')?.strip_prefix(attribute)?;
+        value
+            .starts_with(char::is_whitespace)
+            .then(|| parse_string_literal(value.trim()))?
+    })
+}
+
+fn parse_string_literal(value: &str) -> Option<String> {
+    let value = value.strip_prefix('"')?;
+
+    Some(value[..value.find('"')?].to_string())
+}
+
+/// Keys of keyword list returned by `aliases` function, e.g. `setup:` or `"ecto.reset":`
+fn parse_aliases(content: &str) -> Vec<String> {
+    let Some(start) = ["defp aliases do", "def aliases do"]
+        .iter()
+        .find_map(|definition| content.find(definition))
+    else {
+        return vec![];
+    };
+    let Some(list_start) = content[start..].find('[') else {
+        return vec![];
+    };
+
+    let mut aliases = vec![];
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut expect_key = false;
+    let mut chars = content[start + list_start..].chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        if expect_key && depth == 1 && !c.is_whitespace() {
+            expect_key = false;
+
+            let key: String = if c == '"' {
+                chars.by_ref().take_while(|c| *c != '"').collect()
+            } else {
+                std::iter::once(c)
+                    .chain(std::iter::from_fn(|| {
+                        chars.next_if(|c| c.is_alphanumeric() || "_.?!".contains(*c))
+                    }))
+                    .collect()
+            };
+
+            if chars.next_if_eq(&':').is_some() {
+                aliases.push(key);
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '[' | '{' | '(' => {
+                depth += 1;
+                expect_key = depth == 1;
+            }
+            ']' | '}' | ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            ',' => expect_key = depth == 1,
+            _ => {}
+        }
+    }
+
+    aliases
+}
+
+impl PackageInfoExtractor for MixPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    fn get_scripts(&self) -> Vec<String> {
+        self.aliases.clone()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = MixPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_success() {
+    let notifier_path =
+        crate::testing::utils::get_repo_root().join("./references/elixir-sample/services/notifier");
+    let result = MixPackageInfoExtractor::from_package_path(&notifier_path).unwrap();
+    assert_eq!(result.get_name(), "notifier");
+    assert_eq!(result.get_version(), "0.9.1");
+    assert_eq!(result.aliases, vec!["setup", "ecto.reset", "lint"]);
+}
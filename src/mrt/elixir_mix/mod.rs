@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{info::MixPackageInfoExtractor, runner::MixScriptRunner};
+
+mod info;
+mod runner;
+
+pub struct ElixirMixArchetype {}
+
+impl Archetype for ElixirMixArchetype {
+    fn get_id(&self) -> &str {
+        "elixir/mix"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join("mix.exs").exists()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(MixScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            MixPackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
+
+#[test]
+fn test_script_runner_explain() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/elixir-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./services/notifier"))?;
+
+    assert_eq!(package.archetype_id, "elixir/mix");
+
+    let archetype = ElixirMixArchetype {};
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
+
+    let explain = |script_spec: &str| {
+        let context = crate::runners::ScriptRunContext {
+            script_spec,
+            script_args: &[],
+            package: &package,
+            reporter: &crate::progress::LogProgressReporter {},
+            cache: &crate::runners::ScriptRunCache::default(),
+        };
+        crate::runners::ScriptRunExplanation::new(runner.explain_script(&context))
+            .selected_runner_id
+    };
+
+    assert_eq!(explain("lint").as_deref(), Some("mix"));
+    assert_eq!(explain("build").as_deref(), Some("mix"));
+    assert_eq!(explain("deploy"), None);
+
+    Ok(())
+}
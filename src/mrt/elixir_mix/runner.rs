@@ -0,0 +1,55 @@
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+use anyhow::Result;
+
+use super::info::MixPackageInfoExtractor;
+
+/// Runs aliases from mix.exs, and maps common script names to built-in Mix tasks
+pub struct MixScriptRunner {
+    mix_runner: CommandRunner,
+}
+
+impl MixScriptRunner {
+    pub fn new() -> MixScriptRunner {
+        MixScriptRunner {
+            mix_runner: CommandRunner::new("mix".to_string()),
+        }
+    }
+
+    fn get_task<'a>(info: &MixPackageInfoExtractor, script_spec: &'a str) -> Option<&'a str> {
+        if info.has_alias(script_spec) {
+            return Some(script_spec);
+        }
+
+        match script_spec {
+            "build" => Some("compile"),
+            "test" => Some("test"),
+            "format" => Some("format"),
+            "clean" => Some("clean"),
+            _ => None,
+        }
+    }
+}
+
+impl ScriptRunner for MixScriptRunner {
+    fn get_id(&self) -> &str {
+        "mix"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let info = MixPackageInfoExtractor::from_package_path(&context.package.absolute_path)?;
+        let Some(task) = Self::get_task(&info, context.script_spec) else {
+            return Ok(ScriptRunResult::noop());
+        };
+
+        let mut args = vec![task.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        self.mix_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        let info = MixPackageInfoExtractor::from_package_path(&context.package.absolute_path)?;
+
+        Ok(Self::get_task(&info, context.script_spec).is_some())
+    }
+}
@@ -0,0 +1,126 @@
+use anyhow::{Context, Result};
+use std::{path::Path, process::Command};
+
+use crate::package::PackageInfoExtractor;
+
+pub struct GoModuleInfoExtractor {
+    module_path: String,
+    version: String,
+}
+
+impl GoModuleInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<GoModuleInfoExtractor> {
+        let go_mod_path = package_path.join("go.mod");
+
+        let go_mod_content = std::fs::read_to_string(&go_mod_path)
+            .with_context(|| format!("Failed to read go.mod file at {}", go_mod_path.display()))?;
+
+        let module_path = parse_module_path(&go_mod_content).with_context(|| {
+            format!(
+                "Failed to find module directive in go.mod file at {}",
+                go_mod_path.display()
+            )
+        })?;
+
+        let version = read_version_file(package_path)
+            .or_else(|| read_version_tag(package_path))
+            .unwrap_or_else(|| String::from("n/a"));
+
+        Ok(GoModuleInfoExtractor {
+            module_path,
+            version,
+        })
+    }
+}
+
+fn parse_module_path(go_mod_content: &str) -> Option<String> {
+    go_mod_content.lines().find_map(|line| {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        let module_path = line.strip_prefix("module")?.trim();
+
+        Some(module_path.trim_matches('"').to_string()).filter(|path| !path.is_empty())
+    })
+}
+
+/// Last element of the module path, without major version suffix like `/v2`
+fn get_module_name(module_path: &str) -> &str {
+    let mut elements = module_path.rsplit('/');
+    let last = elements.next().unwrap_or(module_path);
+
+    let is_major_version =
+        last.len() > 1 && last.starts_with('v') && last[1..].chars().all(|c| c.is_ascii_digit());
+
+    match (is_major_version, elements.next()) {
+        (true, Some(name)) => name,
+        _ => last,
+    }
+}
+
+fn read_version_file(package_path: &Path) -> Option<String> {
+    let version = std::fs::read_to_string(package_path.join("VERSION")).ok()?;
+
+    Some(version.trim().to_string()).filter(|version| !version.is_empty())
+}
+
+/// Latest tag of the module, tags of nested modules are prefixed with module directory
+/// like `apps/billing/v1.2.3`
+fn read_version_tag(package_path: &Path) -> Option<String> {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(package_path)
+            .output()
+            .ok()?;
+
+        match output.status.success() {
+            true => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+            false => None,
+        }
+    };
+
+    let prefix = git(&["rev-parse", "--show-prefix"])?;
+    let tag_pattern = format!("{}v[0-9]*", prefix);
+    let tag = git(&["describe", "--tags", "--abbrev=0", "--match", &tag_pattern])?;
+
+    tag.strip_prefix(&prefix)
+        .and_then(|version| version.strip_prefix('v'))
+        .map(str::to_string)
+}
+
+impl PackageInfoExtractor for GoModuleInfoExtractor {
+    fn get_name(&self) -> &str {
+        get_module_name(&self.module_path)
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = GoModuleInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_success() {
+    let billing_path =
+        crate::testing::utils::get_repo_root().join("./references/go-sample/apps/billing");
+    let result = GoModuleInfoExtractor::from_package_path(&billing_path).unwrap();
+    assert_eq!(
+        result.module_path,
+        "github.com/acme/backend/apps/billing/v2"
+    );
+    assert_eq!(result.get_name(), "billing");
+    assert_eq!(result.get_version(), "2.4.0");
+}
+
+#[test]
+fn test_get_module_name() {
+    assert_eq!(get_module_name("github.com/acme/gateway"), "gateway");
+    assert_eq!(get_module_name("github.com/acme/gateway/v3"), "gateway");
+    assert_eq!(get_module_name("example.com/vault"), "vault");
+    assert_eq!(get_module_name("tools"), "tools");
+}
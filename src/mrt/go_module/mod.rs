@@ -0,0 +1,80 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    package_manifest::PackageManifestScriptRunner,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{info::GoModuleInfoExtractor, runner::GoScriptRunner};
+
+mod info;
+mod runner;
+
+pub struct GoModuleArchetype {}
+
+impl Archetype for GoModuleArchetype {
+    fn get_id(&self) -> &str {
+        "go/module"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join("go.mod").exists()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        // Scripts from package `.mrt.yml` are declared for MRT, so they override generic
+        // runners and Go defaults, e.g. `generate: go generate ./...`
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(GoScriptRunner::new()))
+                .with_first(Box::from(PackageManifestScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor = GoModuleInfoExtractor::from_package_path(package_path).context(format!(
+            "Get information extractor for package {}",
+            package_path.display()
+        ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
+
+#[test]
+fn test_script_runner_explain() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/go-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./apps/billing"))?;
+
+    assert_eq!(package.archetype_id, "go/module");
+
+    let archetype = GoModuleArchetype {};
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
+
+    let explain = |script_spec: &str| {
+        let context = crate::runners::ScriptRunContext {
+            script_spec,
+            script_args: &[],
+            package: &package,
+            reporter: &crate::progress::LogProgressReporter {},
+            cache: &crate::runners::ScriptRunCache::default(),
+        };
+        crate::runners::ScriptRunExplanation::new(runner.explain_script(&context))
+            .selected_runner_id
+    };
+
+    // Makefile has `generate` too, `.mrt.yml` scripts win over generic runners
+    assert_eq!(explain("generate").as_deref(), Some("mrt"));
+    assert_eq!(explain("lint").as_deref(), Some("make"));
+    assert_eq!(explain("test").as_deref(), Some("go"));
+    assert_eq!(explain("deploy"), None);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anyhow::Result;
+
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+/// Maps common script names to Go toolchain commands
+pub struct GoScriptRunner {}
+
+impl GoScriptRunner {
+    pub fn new() -> GoScriptRunner {
+        GoScriptRunner {}
+    }
+
+    fn get_command(script_spec: &str) -> Option<(&'static str, Vec<&'static str>)> {
+        match script_spec {
+            "build" => Some(("go", vec!["build", "./..."])),
+            "test" => Some(("go", vec!["test", "./..."])),
+            "lint" => Some(("go", vec!["vet", "./..."])),
+            "format" => Some(("gofmt", vec!["-l", "-w", "."])),
+            _ => None,
+        }
+    }
+}
+
+impl ScriptRunner for GoScriptRunner {
+    fn get_id(&self) -> &str {
+        "go"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        match Self::get_command(context.script_spec) {
+            Some((program, args)) => {
+                let mut args: Vec<String> = args.into_iter().map(str::to_string).collect();
+                args.extend(context.script_args.iter().cloned());
+
+                CommandRunner::new(program.to_string()).run_script(args, context)
+            }
+            None => Ok(ScriptRunResult::noop()),
+        }
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(Self::get_command(context.script_spec).is_some())
+    }
+}
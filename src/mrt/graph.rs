@@ -0,0 +1,502 @@
+use anyhow::{Result, bail};
+use std::collections::{HashMap, VecDeque};
+
+use crate::package::Package;
+use crate::pyproject::normalize_name;
+
+/// Dependencies between packages of the repository, dependencies on packages outside
+/// of it are left out. Packages are referenced by their index in `get_packages`.
+#[derive(Debug, Clone)]
+pub struct PackageGraph {
+    packages: Vec<Package>,
+    dependencies: Vec<Vec<usize>>,
+    dependents: Vec<Vec<usize>>,
+}
+
+/// Package selector in pnpm style: `name`, `name...` with its dependencies,
+/// `...name` with its dependents, `^` excludes the package itself, like `name^...`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageSelector {
+    /// Package name, name glob, or path relative to the project root
+    pub pattern: String,
+    pub dependencies: bool,
+    pub dependents: bool,
+    pub exclude_self: bool,
+}
+
+impl PackageSelector {
+    pub fn parse(selector: &str) -> PackageSelector {
+        let (dependents, pattern) = match selector.strip_prefix("...") {
+            Some(pattern) => (true, pattern),
+            None => (false, selector),
+        };
+        let (dependencies, pattern) = match pattern.strip_suffix("...") {
+            Some(pattern) => (true, pattern),
+            None => (false, pattern),
+        };
+
+        // `^` sits between the package and the dots: `...^name` or `name^...`
+        let mut exclude_self = false;
+        let mut pattern = pattern;
+        if dependents && let Some(stripped) = pattern.strip_prefix('^') {
+            pattern = stripped;
+            exclude_self = true;
+        }
+        if dependencies && let Some(stripped) = pattern.strip_suffix('^') {
+            pattern = stripped;
+            exclude_self = true;
+        }
+
+        PackageSelector {
+            pattern: pattern.to_string(),
+            dependencies,
+            dependents,
+            exclude_self,
+        }
+    }
+
+    fn matches(&self, package: &Package) -> bool {
+        package.matches_name_or_path(&self.pattern)
+            || glob::Pattern::new(&self.pattern).is_ok_and(|pattern| pattern.matches(&package.name))
+    }
+}
+
+/// Name a dependency is matched by, Python names are compared normalized
+fn match_key(package: &Package, name: &str) -> String {
+    if package.archetype_id.starts_with("python/") {
+        normalize_name(name)
+    } else {
+        name.to_string()
+    }
+}
+
+impl PackageGraph {
+    pub fn new(packages: Vec<Package>) -> PackageGraph {
+        let mut indices: HashMap<String, usize> = HashMap::new();
+        for (index, package) in packages.iter().enumerate() {
+            indices
+                .entry(match_key(package, &package.name))
+                .or_insert(index);
+        }
+
+        let mut dependencies = vec![vec![]; packages.len()];
+        let mut dependents = vec![vec![]; packages.len()];
+        for (index, package) in packages.iter().enumerate() {
+            for name in &package.metadata.dependencies {
+                // Packages may depend on themselves, e.g. Python extras
+                if let Some(&dependency) = indices.get(&match_key(package, name))
+                    && dependency != index
+                    && !dependencies[index].contains(&dependency)
+                {
+                    dependencies[index].push(dependency);
+                    dependents[dependency].push(index);
+                }
+            }
+        }
+
+        PackageGraph {
+            packages,
+            dependencies,
+            dependents,
+        }
+    }
+
+    pub fn get_packages(&self) -> &[Package] {
+        &self.packages
+    }
+
+    /// Packages the package depends on
+    pub fn get_dependencies(&self, index: usize) -> &[usize] {
+        &self.dependencies[index]
+    }
+
+    /// Packages that depend on the package
+    pub fn get_dependents(&self, index: usize) -> &[usize] {
+        &self.dependents[index]
+    }
+
+    pub fn find_package(&self, name_or_path: &str) -> Option<usize> {
+        self.packages
+            .iter()
+            .position(|package| package.matches_name_or_path(name_or_path))
+    }
+
+    /// Package and packages reachable from it, up to `depth` edges away when set
+    pub fn select(
+        &self,
+        index: usize,
+        depth: Option<usize>,
+        dependencies: bool,
+        dependents: bool,
+    ) -> Vec<usize> {
+        let mut selected = vec![index];
+        let mut queue = VecDeque::from([(index, 0)]);
+
+        while let Some((current, distance)) = queue.pop_front() {
+            if depth.is_some_and(|depth| distance >= depth) {
+                continue;
+            }
+
+            let mut neighbours: Vec<usize> = vec![];
+            if dependencies {
+                neighbours.extend(self.get_dependencies(current));
+            }
+            if dependents {
+                neighbours.extend(self.get_dependents(current));
+            }
+
+            for neighbour in neighbours {
+                if !selected.contains(&neighbour) {
+                    selected.push(neighbour);
+                    queue.push_back((neighbour, distance + 1));
+                }
+            }
+        }
+
+        selected
+    }
+
+    /// Packages matched by the selector, in package order
+    pub fn select_packages(&self, selector: &PackageSelector) -> Vec<usize> {
+        let matched: Vec<usize> = (0..self.packages.len())
+            .filter(|&index| selector.matches(&self.packages[index]))
+            .collect();
+
+        let mut selected = vec![false; self.packages.len()];
+        for &index in &matched {
+            for node in self
+                .select(index, None, selector.dependencies, false)
+                .into_iter()
+                .chain(self.select(index, None, false, selector.dependents))
+            {
+                if !(selector.exclude_self && node == index) {
+                    selected[node] = true;
+                }
+            }
+        }
+
+        (0..self.packages.len())
+            .filter(|&index| selected[index])
+            .collect()
+    }
+
+    /// Groups of packages that depend on each other, directly or transitively
+    pub fn get_cycles(&self) -> Vec<Vec<usize>> {
+        self.get_strongly_connected_components()
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .collect()
+    }
+
+    /// Shortest path from the first package of the cycle back to it, e.g. `[a, b, a]`,
+    /// each package depends on the next one
+    pub fn get_cycle_path(&self, cycle: &[usize]) -> Vec<usize> {
+        let Some(&start) = cycle.first() else {
+            return vec![];
+        };
+
+        let mut previous: HashMap<usize, usize> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            for &dependency in self.get_dependencies(current) {
+                if !cycle.contains(&dependency) || previous.contains_key(&dependency) {
+                    continue;
+                }
+                previous.insert(dependency, current);
+                if dependency == start {
+                    queue.clear();
+                    break;
+                }
+                queue.push_back(dependency);
+            }
+        }
+
+        // Walk back from the start to itself
+        let mut path = vec![start];
+        let mut current = start;
+        while let Some(&node) = previous.get(&current) {
+            path.push(node);
+            if node == start {
+                break;
+            }
+            current = node;
+        }
+        path.reverse();
+        path
+    }
+
+    /// Readable path of the cycle, like `a -> b -> a`
+    pub fn describe_cycle(&self, cycle: &[usize]) -> String {
+        self.get_cycle_path(cycle)
+            .iter()
+            .map(|&index| self.packages[index].name.as_str())
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+
+    /// Packages grouped so that each group only depends on previous groups.
+    ///
+    /// Cycles are reported as an error, unless `allow_cycles` is set: then packages of
+    /// a cycle share a group, after the other packages they depend on.
+    pub fn get_levels(&self, allow_cycles: bool) -> Result<Vec<Vec<usize>>> {
+        let components = self.get_strongly_connected_components();
+
+        let cycles: Vec<String> = components
+            .iter()
+            .filter(|component| component.len() > 1)
+            .map(|component| self.describe_cycle(component))
+            .collect();
+        if !cycles.is_empty() && !allow_cycles {
+            bail!("Dependency cycles between packages: {}", cycles.join("; "));
+        }
+
+        // Components come with dependencies first, so their levels are already known
+        let mut levels: Vec<usize> = vec![0; self.packages.len()];
+        for component in &components {
+            let level = component
+                .iter()
+                .flat_map(|&node| self.get_dependencies(node))
+                .filter(|dependency| !component.contains(dependency))
+                .map(|&dependency| levels[dependency] + 1)
+                .max()
+                .unwrap_or(0);
+            for &node in component {
+                levels[node] = level;
+            }
+        }
+
+        let mut groups: Vec<Vec<usize>> = vec![];
+        for (node, &level) in levels.iter().enumerate() {
+            if groups.len() <= level {
+                groups.resize(level + 1, vec![]);
+            }
+            groups[level].push(node);
+        }
+
+        Ok(groups)
+    }
+
+    /// Whether the dependency is a part of a cycle
+    pub fn is_cycle_edge(&self, cycles: &[Vec<usize>], from: usize, to: usize) -> bool {
+        cycles
+            .iter()
+            .any(|cycle| cycle.contains(&from) && cycle.contains(&to))
+    }
+
+    /// Tarjan's algorithm, components come in reverse topological order:
+    /// dependencies before their dependents
+    pub fn get_strongly_connected_components(&self) -> Vec<Vec<usize>> {
+        struct State {
+            index: usize,
+            indices: Vec<Option<usize>>,
+            low_links: Vec<usize>,
+            on_stack: Vec<bool>,
+            stack: Vec<usize>,
+            components: Vec<Vec<usize>>,
+        }
+
+        fn connect(graph: &PackageGraph, node: usize, state: &mut State) {
+            state.indices[node] = Some(state.index);
+            state.low_links[node] = state.index;
+            state.index += 1;
+            state.stack.push(node);
+            state.on_stack[node] = true;
+
+            for &dependency in graph.get_dependencies(node) {
+                match state.indices[dependency] {
+                    None => {
+                        connect(graph, dependency, state);
+                        state.low_links[node] =
+                            state.low_links[node].min(state.low_links[dependency]);
+                    }
+                    Some(index) if state.on_stack[dependency] => {
+                        state.low_links[node] = state.low_links[node].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if Some(state.low_links[node]) == state.indices[node] {
+                let mut component = vec![];
+                while let Some(member) = state.stack.pop() {
+                    state.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.reverse();
+                state.components.push(component);
+            }
+        }
+
+        let mut state = State {
+            index: 0,
+            indices: vec![None; self.packages.len()],
+            low_links: vec![0; self.packages.len()],
+            on_stack: vec![false; self.packages.len()],
+            stack: vec![],
+            components: vec![],
+        };
+
+        for node in 0..self.packages.len() {
+            if state.indices[node].is_none() {
+                connect(self, node, &mut state);
+            }
+        }
+
+        state.components
+    }
+}
+
+#[cfg(test)]
+fn get_sample_graph() -> PackageGraph {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/graph-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path)).unwrap();
+
+    PackageGraph::new(project.get_packages(false))
+}
+
+#[cfg(test)]
+fn get_names(graph: &PackageGraph, indices: &[usize]) -> Vec<String> {
+    let mut names: Vec<String> = indices
+        .iter()
+        .map(|&index| graph.get_packages()[index].name.clone())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_dependencies() {
+    let graph = get_sample_graph();
+
+    let web = graph.find_package("web").unwrap();
+    assert_eq!(
+        get_names(&graph, graph.get_dependencies(web)),
+        vec!["@acme/core", "@acme/ui"]
+    );
+
+    // Python names are matched normalized, `acme.utils` is `Acme_Utils`
+    let api = graph.find_package("packages/py-api").unwrap();
+    assert_eq!(
+        get_names(&graph, graph.get_dependencies(api)),
+        vec!["Acme_Utils"]
+    );
+
+    let core = graph.find_package("@acme/core").unwrap();
+    assert_eq!(
+        get_names(&graph, graph.get_dependents(core)),
+        vec!["@acme/ui", "web"]
+    );
+}
+
+#[test]
+fn test_select() {
+    let graph = get_sample_graph();
+    let ui = graph.find_package("@acme/ui").unwrap();
+
+    assert_eq!(
+        get_names(&graph, &graph.select(ui, None, true, false)),
+        vec!["@acme/core", "@acme/ui"]
+    );
+    assert_eq!(
+        get_names(&graph, &graph.select(ui, None, false, true)),
+        vec!["@acme/ui", "web"]
+    );
+    assert_eq!(
+        get_names(&graph, &graph.select(ui, Some(0), true, true)),
+        vec!["@acme/ui"]
+    );
+}
+
+#[test]
+fn test_get_cycles() {
+    let graph = get_sample_graph();
+    let cycles = graph.get_cycles();
+
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(get_names(&graph, &cycles[0]), vec!["ping", "pong"]);
+
+    let ping = graph.find_package("ping").unwrap();
+    let pong = graph.find_package("pong").unwrap();
+    let web = graph.find_package("web").unwrap();
+    let ui = graph.find_package("@acme/ui").unwrap();
+    assert!(graph.is_cycle_edge(&cycles, ping, pong));
+    assert!(!graph.is_cycle_edge(&cycles, web, ui));
+}
+
+#[test]
+fn test_describe_cycle() {
+    let graph = get_sample_graph();
+    let cycles = graph.get_cycles();
+    let description = graph.describe_cycle(&cycles[0]);
+
+    assert!(description == "ping -> pong -> ping" || description == "pong -> ping -> pong");
+}
+
+#[test]
+fn test_get_levels() {
+    let graph = get_sample_graph();
+
+    let err = graph.get_levels(false).unwrap_err();
+    assert!(err.to_string().contains("ping"));
+
+    let levels = graph.get_levels(true).unwrap();
+    let level_of = |name: &str| {
+        let index = graph.find_package(name).unwrap();
+        levels
+            .iter()
+            .position(|level| level.contains(&index))
+            .unwrap()
+    };
+
+    assert!(level_of("@acme/core") < level_of("@acme/ui"));
+    assert!(level_of("@acme/ui") < level_of("web"));
+    assert!(level_of("Acme_Utils") < level_of("acme-api"));
+    assert_eq!(level_of("ping"), level_of("pong"));
+}
+
+#[test]
+fn test_package_selector_parse() {
+    let selector = PackageSelector::parse("...^@acme/ui");
+    assert_eq!(selector.pattern, "@acme/ui");
+    assert!(selector.dependents && !selector.dependencies && selector.exclude_self);
+
+    let selector = PackageSelector::parse("web...");
+    assert_eq!(selector.pattern, "web");
+    assert!(!selector.dependents && selector.dependencies && !selector.exclude_self);
+
+    let selector = PackageSelector::parse("./packages/ui");
+    assert_eq!(selector.pattern, "./packages/ui");
+    assert!(!selector.dependents && !selector.dependencies);
+}
+
+#[test]
+fn test_select_packages() {
+    let graph = get_sample_graph();
+    let select = |selector: &str| {
+        get_names(
+            &graph,
+            &graph.select_packages(&PackageSelector::parse(selector)),
+        )
+    };
+
+    assert_eq!(select("@acme/ui..."), vec!["@acme/core", "@acme/ui"]);
+    assert_eq!(select("@acme/ui^..."), vec!["@acme/core"]);
+    assert_eq!(
+        select("...@acme/core"),
+        vec!["@acme/core", "@acme/ui", "web"]
+    );
+    assert_eq!(select("...^@acme/core"), vec!["@acme/ui", "web"]);
+    assert_eq!(
+        select("...@acme/ui..."),
+        vec!["@acme/core", "@acme/ui", "web"]
+    );
+    assert_eq!(select("@acme/*"), vec!["@acme/core", "@acme/ui"]);
+    assert_eq!(
+        select("./packages/py-api..."),
+        vec!["Acme_Utils", "acme-api"]
+    );
+}
@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use std::{path::Path, process::Command};
+
+// `just` looks for the file name case-insensitively, these cover common spellings
+const JUSTFILE_NAMES: [&str; 4] = ["justfile", "Justfile", "JUSTFILE", ".justfile"];
+
+pub(crate) fn has_justfile(package_path: &Path) -> bool {
+    JUSTFILE_NAMES
+        .iter()
+        .any(|name| package_path.join(name).is_file())
+}
+
+/// Discover public recipes with `just --summary`, without running any of them
+pub(crate) fn discover_just_recipes(program: &str, package_path: &Path) -> Result<Vec<String>> {
+    if !has_justfile(package_path) {
+        return Ok(vec![]);
+    }
+
+    let output = Command::new(program)
+        .arg("--summary")
+        .current_dir(package_path)
+        .output()
+        .with_context(|| format!("Failed to list just recipes at {}", package_path.display()))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to list just recipes at {}: {}",
+            package_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(parse_just_summary(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Summary is a whitespace separated list of recipes, with `module::recipe` for submodules
+pub(crate) fn parse_just_summary(summary: &str) -> Vec<String> {
+    summary.split_whitespace().map(str::to_string).collect()
+}
+
+#[test]
+fn test_parse_just_summary() {
+    assert_eq!(
+        parse_just_summary("build format test docs::serve\n"),
+        vec!["build", "format", "test", "docs::serve"]
+    );
+    assert!(parse_just_summary("\n").is_empty());
+}
+
+#[test]
+fn test_has_justfile() {
+    let fixture_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("fixures/task-runners");
+
+    assert!(has_justfile(&fixture_path));
+    assert!(!has_justfile(fixture_path.parent().unwrap()));
+}
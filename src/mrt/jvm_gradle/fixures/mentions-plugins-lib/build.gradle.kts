@@ -0,0 +1,10 @@
+plugins {
+    java
+    // checkstyle is enabled once the legacy sources are cleaned up
+}
+
+dependencies {
+    testImplementation("com.diffplug.spotless:spotless-lib:2.45.0")
+}
+
+version = "0.3.0"
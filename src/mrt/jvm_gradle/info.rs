@@ -0,0 +1,114 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+
+use crate::package::PackageInfoExtractor;
+
+pub struct GradlePackageInfoExtractor {
+    name: String,
+    version: String,
+}
+
+impl GradlePackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<GradlePackageInfoExtractor> {
+        let build_file_path = ["build.gradle.kts", "build.gradle"]
+            .iter()
+            .map(|file_name| package_path.join(file_name))
+            .find(|path| path.is_file())
+            .with_context(|| {
+                format!(
+                    "Failed to find build.gradle(.kts) file at {}",
+                    package_path.display()
+                )
+            })?;
+
+        // Gradle project name defaults to the directory name
+        let name = ["settings.gradle.kts", "settings.gradle"]
+            .iter()
+            .filter_map(|file_name| std::fs::read_to_string(package_path.join(file_name)).ok())
+            .find_map(|content| find_assignment(&content, "rootProject.name"))
+            .or_else(|| {
+                package_path
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+            })
+            .unwrap_or_else(|| String::from("n/a"));
+
+        let build_file_content = std::fs::read_to_string(&build_file_path).with_context(|| {
+            format!(
+                "Failed to read Gradle build file at {}",
+                build_file_path.display()
+            )
+        })?;
+
+        let version = std::fs::read_to_string(package_path.join("gradle.properties"))
+            .ok()
+            .and_then(|content| find_property(&content, "version"))
+            .or_else(|| find_assignment(&build_file_content, "version"))
+            .unwrap_or_else(|| String::from("n/a"));
+
+        Ok(GradlePackageInfoExtractor { name, version })
+    }
+}
+
+/// Find `key = "value"` or `key = 'value'` assignment in Groovy or Kotlin DSL script
+fn find_assignment(content: &str, key: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let (left, right) = line.split_once('=')?;
+        if left.trim() != key {
+            return None;
+        }
+
+        let value = right.trim();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+
+        Some(value[..value.find(quote)?].to_string())
+    })
+}
+
+/// Find `key=value` in Java properties file
+fn find_property(content: &str, key: &str) -> Option<String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#') && !line.starts_with('!'))
+        .find_map(|line| {
+            let (left, right) = line.split_once(['=', ':'])?;
+            (left.trim() == key).then(|| right.trim().to_string())
+        })
+}
+
+impl PackageInfoExtractor for GradlePackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = GradlePackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_kotlin_dsl() {
+    let orders_path =
+        crate::testing::utils::get_repo_root().join("./references/jvm-sample/apps/orders");
+    let result = GradlePackageInfoExtractor::from_package_path(&orders_path).unwrap();
+    assert_eq!(result.get_name(), "orders-service");
+    assert_eq!(result.get_version(), "1.4.0");
+}
+
+#[test]
+fn test_from_package_path_groovy_dsl() {
+    let inventory_path =
+        crate::testing::utils::get_repo_root().join("./references/jvm-sample/apps/inventory");
+    let result = GradlePackageInfoExtractor::from_package_path(&inventory_path).unwrap();
+    assert_eq!(result.get_name(), "inventory");
+    assert_eq!(result.get_version(), "0.9.1");
+}
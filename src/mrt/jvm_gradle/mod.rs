@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{info::GradlePackageInfoExtractor, runner::GradleScriptRunner};
+
+mod info;
+mod runner;
+
+pub struct JvmGradleArchetype {}
+
+impl Archetype for JvmGradleArchetype {
+    fn get_id(&self) -> &str {
+        "jvm/gradle"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join("build.gradle").exists() || package_path.join("build.gradle.kts").exists()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(GradleScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            GradlePackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
+
+#[test]
+fn test_script_runner_explain() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/jvm-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./apps/orders"))?;
+
+    assert_eq!(package.archetype_id, "jvm/gradle");
+
+    let archetype = JvmGradleArchetype {};
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
+
+    let context = crate::runners::ScriptRunContext {
+        script_spec: "lint",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+    let explanation = crate::runners::ScriptRunExplanation::new(runner.explain_script(&context));
+
+    assert_eq!(explanation.selected_runner_id.as_deref(), Some("gradle"));
+
+    Ok(())
+}
@@ -0,0 +1,318 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::package::find_in_ancestors;
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+const GRADLE_WRAPPER: &str = if cfg!(windows) {
+    "gradlew.bat"
+} else {
+    "gradlew"
+};
+
+const BUILD_SCRIPT_NAMES: [&str; 2] = ["build.gradle", "build.gradle.kts"];
+
+/// Plugin ids adding checks to the `check` task, without them it only runs tests
+const LINT_PLUGINS: [&str; 5] = [
+    "checkstyle",
+    "pmd",
+    "com.github.spotbugs",
+    "io.gitlab.arturbosch.detekt",
+    "org.jlleitschuh.gradle.ktlint",
+];
+
+/// Plugin ids adding the `spotlessApply` task, current and legacy
+const FORMAT_PLUGINS: [&str; 2] = ["com.diffplug.spotless", "com.diffplug.gradle.spotless"];
+
+/// Maps script names to Gradle tasks, preferring Gradle wrapper over installed Gradle
+pub struct GradleScriptRunner {}
+
+impl GradleScriptRunner {
+    pub fn new() -> GradleScriptRunner {
+        GradleScriptRunner {}
+    }
+
+    /// `lint` and `format` are only mapped when the build script applies a plugin for them
+    fn get_task(script_spec: &str, package_path: &Path) -> Option<&'static str> {
+        let applies_any = |ids: &[&str]| {
+            get_plugin_ids(&read_build_script(package_path))
+                .iter()
+                .any(|id| ids.contains(&id.as_str()))
+        };
+
+        match script_spec {
+            "build" => Some("build"),
+            "test" => Some("test"),
+            "lint" => applies_any(&LINT_PLUGINS).then_some("check"),
+            "format" => applies_any(&FORMAT_PLUGINS).then_some("spotlessApply"),
+            "clean" => Some("clean"),
+            _ => None,
+        }
+    }
+}
+
+fn read_build_script(package_path: &Path) -> String {
+    BUILD_SCRIPT_NAMES
+        .iter()
+        .find_map(|name| std::fs::read_to_string(package_path.join(name)).ok())
+        .unwrap_or_default()
+}
+
+/// Ids of plugins applied in `plugins {}` blocks or with `apply plugin:`, in Groovy and
+/// Kotlin DSL. Version catalog aliases cannot be resolved and are ignored.
+fn get_plugin_ids(build_script: &str) -> Vec<String> {
+    let build_script = strip_comments(build_script);
+    let mut ids = vec![];
+
+    let mut rest = build_script.as_str();
+    while let Some((block, after)) = find_plugins_block(rest) {
+        // `apply false` only puts the plugin on the classpath for subprojects
+        let statements = block
+            .split(['\n', ';'])
+            .map(str::trim)
+            .filter(|statement| !statement.ends_with("apply false"));
+        for statement in statements {
+            if let Some(args) = strip_call(statement, "id") {
+                ids.extend(first_string_literal(args));
+            } else if let Some(args) = strip_call(statement, "kotlin") {
+                ids.extend(
+                    first_string_literal(args).map(|name| format!("org.jetbrains.kotlin.{}", name)),
+                );
+            } else if let Some(name) = statement
+                .split_whitespace()
+                .next()
+                .map(|name| name.trim_matches('`'))
+                .filter(|name| {
+                    !name.is_empty()
+                        && name
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+                })
+            {
+                // Core plugins like `java` or `checkstyle` are applied by name
+                ids.push(name.to_string());
+            }
+        }
+        rest = after;
+    }
+
+    // `apply plugin: 'pmd'` in Groovy, `apply(plugin = "pmd")` in Kotlin DSL
+    let mut rest = build_script.as_str();
+    while let Some(index) = rest.find("apply") {
+        rest = &rest[index + "apply".len()..];
+        let args = rest.trim_start().trim_start_matches('(').trim_start();
+        if let Some(args) = args.strip_prefix("plugin") {
+            let args = args.trim_start();
+            if args.starts_with(':') || args.starts_with('=') {
+                ids.extend(first_string_literal(
+                    args.lines().next().unwrap_or_default(),
+                ));
+            }
+        }
+    }
+
+    ids
+}
+
+/// Arguments of `name(...)` or `name ...` call statement
+fn strip_call<'a>(statement: &'a str, name: &str) -> Option<&'a str> {
+    statement
+        .strip_prefix(name)
+        .filter(|args| args.starts_with(['(', ' ', '\t']))
+}
+
+fn first_string_literal(text: &str) -> Option<String> {
+    let start = text.find(['"', '\''])?;
+    let quote = text[start..].chars().next()?;
+    let value = &text[start + 1..];
+    value.find(quote).map(|end| value[..end].to_string())
+}
+
+/// Contents of the next top level `plugins { ... }` block and the text after it
+fn find_plugins_block(text: &str) -> Option<(&str, &str)> {
+    let mut search_from = 0;
+    while let Some(index) = text[search_from..].find("plugins") {
+        let start = search_from + index;
+        search_from = start + "plugins".len();
+
+        let is_word_start = text[..start]
+            .chars()
+            .next_back()
+            .is_none_or(|c| !c.is_ascii_alphanumeric() && c != '_' && c != '.');
+        let after = text[search_from..].trim_start();
+        if !is_word_start || !after.starts_with('{') {
+            continue;
+        }
+
+        let block_start = text.len() - after.len() + 1;
+        let mut depth = 1;
+        for (offset, c) in text[block_start..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                let block_end = block_start + offset;
+                return Some((&text[block_start..block_end], &text[block_end + 1..]));
+            }
+        }
+        return Some((&text[block_start..], ""));
+    }
+    None
+}
+
+/// Removes `//` and `/* */` comments, keeping string literals like URLs intact
+fn strip_comments(source: &str) -> String {
+    let mut result = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut quote: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if let Some(open) = quote {
+            result.push(c);
+            if c == '\\' {
+                result.extend(chars.next());
+            } else if c == open || c == '\n' {
+                quote = None;
+            }
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('/', Some('/')) => while chars.next_if(|c| *c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    // Keep line breaks so statements stay on their own lines
+                    if c == '\n' {
+                        result.push(c);
+                    }
+                    previous = c;
+                }
+            }
+            ('"' | '\'', _) => {
+                quote = Some(c);
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+impl ScriptRunner for GradleScriptRunner {
+    fn get_id(&self) -> &str {
+        "gradle"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let Some(task) = Self::get_task(context.script_spec, &context.package.absolute_path) else {
+            return Ok(ScriptRunResult::noop());
+        };
+
+        // Wrapper is usually in the root of multi-project build
+        let program = find_in_ancestors(&context.package.absolute_path, GRADLE_WRAPPER)
+            .map(|wrapper| wrapper.to_string_lossy().to_string())
+            .unwrap_or_else(|| "gradle".to_string());
+
+        let mut args = vec![task.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        CommandRunner::new(program).run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(Self::get_task(context.script_spec, &context.package.absolute_path).is_some())
+    }
+}
+
+#[test]
+fn test_get_task() {
+    let apps_path = crate::testing::utils::get_repo_root().join("./references/jvm-sample/apps");
+
+    // orders applies Checkstyle and Spotless, inventory only the Java plugin
+    let orders_path = apps_path.join("orders");
+    assert_eq!(
+        GradleScriptRunner::get_task("lint", &orders_path),
+        Some("check")
+    );
+    assert_eq!(
+        GradleScriptRunner::get_task("format", &orders_path),
+        Some("spotlessApply")
+    );
+
+    let inventory_path = apps_path.join("inventory");
+    assert_eq!(
+        GradleScriptRunner::get_task("build", &inventory_path),
+        Some("build")
+    );
+    assert_eq!(GradleScriptRunner::get_task("lint", &inventory_path), None);
+    assert_eq!(
+        GradleScriptRunner::get_task("format", &inventory_path),
+        None
+    );
+    assert_eq!(
+        GradleScriptRunner::get_task("deploy", &inventory_path),
+        None
+    );
+}
+
+#[test]
+fn test_get_plugin_ids() {
+    let kotlin_script = r#"
+        plugins {
+            java
+            `java-library`
+            kotlin("jvm") version "2.0.0"
+            id("com.github.spotbugs") version "6.0.0"; id("com.diffplug.spotless")
+            id("io.gitlab.arturbosch.detekt") version "1.23.6" apply false
+            // id("io.gitlab.arturbosch.detekt")
+        }
+        apply(plugin = "pmd")
+    "#;
+    assert_eq!(
+        get_plugin_ids(kotlin_script),
+        vec![
+            "java",
+            "java-library",
+            "org.jetbrains.kotlin.jvm",
+            "com.github.spotbugs",
+            "com.diffplug.spotless",
+            "pmd"
+        ]
+    );
+
+    let groovy_script = r#"
+        plugins {
+            id 'java'
+            /* id 'checkstyle' */
+        }
+        apply plugin: 'org.jlleitschuh.gradle.ktlint'
+        repositories { maven { url "https://repo.acme.com/pmd" } }
+    "#;
+    assert_eq!(
+        get_plugin_ids(groovy_script),
+        vec!["java", "org.jlleitschuh.gradle.ktlint"]
+    );
+}
+
+#[test]
+fn test_get_task_ignores_plugin_names_outside_plugins() {
+    // Checkstyle only in a comment, Spotless only in a dependency coordinate
+    let package_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("./fixures/mentions-plugins-lib");
+
+    assert_eq!(
+        GradleScriptRunner::get_task("build", &package_path),
+        Some("build")
+    );
+    assert_eq!(GradleScriptRunner::get_task("lint", &package_path), None);
+    assert_eq!(GradleScriptRunner::get_task("format", &package_path), None);
+}
@@ -0,0 +1,151 @@
+use anyhow::{Context, Result};
+use roxmltree::{Document, Node};
+use std::{collections::HashMap, path::Path};
+
+use crate::package::PackageInfoExtractor;
+
+pub struct MavenPackageInfoExtractor {
+    name: String,
+    version: String,
+    dependencies: Vec<String>,
+}
+
+fn get_child_text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.children()
+        .find(|child| child.tag_name().name() == name)
+        .and_then(|child| child.text())
+        .map(str::trim)
+}
+
+/// Replace `${property}` references with values from `<properties>`, e.g. CI friendly `${revision}`
+fn resolve_properties(value: &str, properties: &HashMap<&str, &str>) -> String {
+    let mut resolved = value.to_string();
+
+    for (key, property_value) in properties {
+        resolved = resolved.replace(&format!("${{{}}}", key), property_value);
+    }
+
+    resolved
+}
+
+impl MavenPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<MavenPackageInfoExtractor> {
+        let pom_xml_path = package_path.join("pom.xml");
+
+        let pom_xml_content = std::fs::read_to_string(&pom_xml_path).with_context(|| {
+            format!("Failed to read pom.xml file at {}", pom_xml_path.display())
+        })?;
+
+        let document = Document::parse(&pom_xml_content).with_context(|| {
+            format!(
+                "Failed to parse XML of pom.xml file at {}",
+                pom_xml_path.display()
+            )
+        })?;
+        let project = document.root_element();
+        let parent = project
+            .children()
+            .find(|child| child.tag_name().name() == "parent");
+
+        let properties: HashMap<&str, &str> = project
+            .children()
+            .find(|child| child.tag_name().name() == "properties")
+            .map(|properties| {
+                properties
+                    .children()
+                    .filter(Node::is_element)
+                    .map(|property| {
+                        (
+                            property.tag_name().name(),
+                            property.text().unwrap_or_default().trim(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // groupId and version are inherited from parent when not declared
+        let inherited = |name: &str| {
+            get_child_text(project, name).or_else(|| parent.and_then(|p| get_child_text(p, name)))
+        };
+
+        let artifact_id = get_child_text(project, "artifactId").with_context(|| {
+            format!(
+                "Failed to find artifactId in pom.xml file at {}",
+                pom_xml_path.display()
+            )
+        })?;
+
+        let name = match inherited("groupId") {
+            Some(group_id) => format!("{}:{}", group_id, artifact_id),
+            None => artifact_id.to_string(),
+        };
+
+        let version = inherited("version")
+            .map(|version| resolve_properties(version, &properties))
+            .unwrap_or_else(|| String::from("n/a"));
+
+        // Dependencies are named `groupId:artifactId`, same as packages
+        let mut properties = properties;
+        if let Some(group_id) = inherited("groupId") {
+            properties.insert("project.groupId", group_id);
+        }
+        let dependencies = project
+            .children()
+            .find(|child| child.tag_name().name() == "dependencies")
+            .into_iter()
+            .flat_map(|dependencies| dependencies.children())
+            .filter(|dependency| dependency.tag_name().name() == "dependency")
+            .filter_map(|dependency| {
+                Some(resolve_properties(
+                    &format!(
+                        "{}:{}",
+                        get_child_text(dependency, "groupId")?,
+                        get_child_text(dependency, "artifactId")?
+                    ),
+                    &properties,
+                ))
+            })
+            .collect();
+
+        Ok(MavenPackageInfoExtractor {
+            name,
+            version,
+            dependencies,
+        })
+    }
+}
+
+impl PackageInfoExtractor for MavenPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    fn get_dependencies(&self) -> Vec<String> {
+        self.dependencies.clone()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = MavenPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_success() {
+    let payments_path =
+        crate::testing::utils::get_repo_root().join("./references/jvm-sample/apps/payments");
+    let result = MavenPackageInfoExtractor::from_package_path(&payments_path).unwrap();
+    assert_eq!(result.get_name(), "com.acme:payments-service");
+    assert_eq!(result.get_version(), "2.2.0");
+    assert_eq!(
+        result.get_dependencies(),
+        vec!["com.acme:ledger-api", "org.junit.jupiter:junit-jupiter"]
+    );
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{info::MavenPackageInfoExtractor, runner::MavenScriptRunner};
+
+mod info;
+mod runner;
+
+pub struct JvmMavenArchetype {}
+
+impl Archetype for JvmMavenArchetype {
+    fn get_id(&self) -> &str {
+        "jvm/maven"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join("pom.xml").exists()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(MavenScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            MavenPackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
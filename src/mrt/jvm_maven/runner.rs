@@ -0,0 +1,130 @@
+use anyhow::{Context, Result};
+use roxmltree::Document;
+use std::path::Path;
+
+use crate::package::find_in_ancestors;
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+const MAVEN_WRAPPER: &str = if cfg!(windows) { "mvnw.cmd" } else { "mvnw" };
+
+/// Plugins with a check goal bound to the `verify` phase
+const LINT_PLUGINS: [&str; 3] = [
+    "maven-checkstyle-plugin",
+    "maven-pmd-plugin",
+    "spotbugs-maven-plugin",
+];
+
+/// Maps script names to Maven lifecycle phases, preferring Maven wrapper over installed Maven
+pub struct MavenScriptRunner {}
+
+impl MavenScriptRunner {
+    pub fn new() -> MavenScriptRunner {
+        MavenScriptRunner {}
+    }
+
+    /// `lint` and `format` are only mapped when the POM declares a plugin for them
+    fn get_phase(script_spec: &str, package_path: &Path) -> Result<Option<&'static str>> {
+        Ok(match script_spec {
+            "build" => Some("package"),
+            "test" => Some("test"),
+            "lint" => {
+                let plugins = get_build_plugins(package_path)?;
+                LINT_PLUGINS
+                    .iter()
+                    .any(|plugin| plugins.iter().any(|declared| declared == plugin))
+                    .then_some("verify")
+            }
+            "format" => get_build_plugins(package_path)?
+                .iter()
+                .any(|declared| declared == "spotless-maven-plugin")
+                .then_some("spotless:apply"),
+            "install" => Some("install"),
+            "clean" => Some("clean"),
+            _ => None,
+        })
+    }
+}
+
+/// Artifact ids of build plugins, `<pluginManagement>` only configures plugins without using them
+fn get_build_plugins(package_path: &Path) -> Result<Vec<String>> {
+    let pom_xml_path = package_path.join("pom.xml");
+    let pom_xml_content = std::fs::read_to_string(&pom_xml_path)
+        .with_context(|| format!("Failed to read pom.xml file at {}", pom_xml_path.display()))?;
+    let document = Document::parse(&pom_xml_content).with_context(|| {
+        format!(
+            "Failed to parse XML of pom.xml file at {}",
+            pom_xml_path.display()
+        )
+    })?;
+
+    Ok(document
+        .descendants()
+        .filter(|node| node.tag_name().name() == "plugin")
+        .filter(|plugin| {
+            !plugin
+                .ancestors()
+                .any(|ancestor| ancestor.tag_name().name() == "pluginManagement")
+        })
+        .filter_map(|plugin| {
+            plugin
+                .children()
+                .find(|child| child.tag_name().name() == "artifactId")
+                .and_then(|artifact_id| artifact_id.text())
+                .map(|artifact_id| artifact_id.trim().to_string())
+        })
+        .collect())
+}
+
+impl ScriptRunner for MavenScriptRunner {
+    fn get_id(&self) -> &str {
+        "maven"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let Some(phase) = Self::get_phase(context.script_spec, &context.package.absolute_path)?
+        else {
+            return Ok(ScriptRunResult::noop());
+        };
+
+        // Wrapper is usually in the root of multi-module build
+        let program = find_in_ancestors(&context.package.absolute_path, MAVEN_WRAPPER)
+            .map(|wrapper| wrapper.to_string_lossy().to_string())
+            .unwrap_or_else(|| "mvn".to_string());
+
+        let mut args = vec![phase.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        CommandRunner::new(program).run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(Self::get_phase(context.script_spec, &context.package.absolute_path)?.is_some())
+    }
+}
+
+#[test]
+fn test_maven_script_runner() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/jvm-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./apps/payments"))?;
+    let runner = MavenScriptRunner::new();
+    let can_run = |script_spec| {
+        runner.can_run_script(&ScriptRunContext {
+            script_spec,
+            script_args: &[],
+            package: &package,
+            reporter: &crate::progress::LogProgressReporter {},
+            cache: &crate::runners::ScriptRunCache::default(),
+        })
+    };
+
+    assert!(can_run("build")?);
+    assert!(can_run("install")?);
+    // Checkstyle is a build plugin, Spotless is only in `<pluginManagement>`
+    assert!(can_run("lint")?);
+    assert!(!can_run("format")?);
+    assert!(!can_run("deploy")?);
+
+    Ok(())
+}
@@ -1,9 +1,29 @@
 pub mod archetypes;
+pub mod codeowners;
+mod cpp_cmake;
+mod cpp_meson;
+mod deno_package;
+mod dotnet_project;
+mod elixir_mix;
+mod go_module;
+pub mod graph;
+mod just;
+mod jvm_gradle;
+mod jvm_maven;
+mod make;
 pub mod manifest;
 mod nodejs_npm;
 pub mod package;
+mod package_manifest;
+mod php_composer;
+pub mod plugins;
 pub mod progress;
 pub mod project;
+mod pyproject;
+mod python_pep621;
 mod python_poetry;
+mod ruby_bundler;
 pub mod runners;
+mod taskfile;
 mod testing;
+pub mod workspaces;
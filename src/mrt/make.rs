@@ -0,0 +1,200 @@
+use anyhow::Result;
+use std::{path::Path, process::Command};
+
+const MAKEFILE_NAMES: [&str; 3] = ["GNUmakefile", "makefile", "Makefile"];
+
+const DATABASE_SECTIONS: [&str; 6] = [
+    "# Variables",
+    "# Pattern-specific Variable Values",
+    "# Directories",
+    "# Implicit Rules",
+    "# Files",
+    "# VPATH Search Paths",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum MakeTargetKind {
+    /// Prerequisite of `.PHONY`
+    Phony,
+    /// Explicit target that does not look like a file
+    Target,
+    /// Explicit target that names a file, e.g. `out.txt` or `dist/app`
+    File,
+    /// Pattern rule, e.g. `%.o: %.c`
+    Pattern,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MakeTarget {
+    pub name: String,
+    pub kind: MakeTargetKind,
+}
+
+impl MakeTarget {
+    /// Only phony and plain targets are treated as scripts
+    pub fn is_script(&self) -> bool {
+        matches!(self.kind, MakeTargetKind::Phony | MakeTargetKind::Target)
+    }
+}
+
+pub(crate) fn has_makefile(package_path: &Path) -> bool {
+    MAKEFILE_NAMES
+        .iter()
+        .any(|name| package_path.join(name).is_file())
+}
+
+/// Discover targets from make database, without executing any recipes
+pub(crate) fn discover_make_targets(program: &str, package_path: &Path) -> Result<Vec<MakeTarget>> {
+    if !has_makefile(package_path) {
+        return Ok(vec![]);
+    }
+
+    // -p prints the database, -q makes sure nothing is executed,
+    // -R/-r drops built-in variables and rules so only Makefile targets are listed
+    let output = Command::new(program)
+        .args(["-pRrq", ":"])
+        .current_dir(package_path)
+        .output()?;
+
+    let database = String::from_utf8_lossy(&output.stdout);
+
+    Ok(parse_make_database(&database))
+}
+
+pub(crate) fn parse_make_database(database: &str) -> Vec<MakeTarget> {
+    let mut targets = vec![];
+    let mut section = "";
+    let mut not_a_target = false;
+    let mut current: Option<MakeTarget> = None;
+
+    for line in database.lines() {
+        if DATABASE_SECTIONS.contains(&line) {
+            targets.extend(current.take());
+            section = line;
+            continue;
+        }
+        if line.starts_with("# Finished Make data base") {
+            break;
+        }
+
+        if line.starts_with("# Not a target:") {
+            not_a_target = true;
+            continue;
+        }
+
+        if line.starts_with("#  Phony target") {
+            if let Some(target) = current.as_mut() {
+                target.kind = MakeTargetKind::Phony;
+            }
+            continue;
+        }
+
+        if line.is_empty() {
+            targets.extend(current.take());
+            not_a_target = false;
+            continue;
+        }
+
+        if line.starts_with('#') || line.starts_with('\t') || line.starts_with(' ') {
+            continue;
+        }
+
+        let Some((name, _prerequisites)) = line.split_once(':') else {
+            continue;
+        };
+        let name = name.trim();
+
+        // Special targets like `.PHONY`, command line `:` goal, and variable assignments
+        if not_a_target || name.is_empty() || name.starts_with('.') || line.contains(":=") {
+            continue;
+        }
+
+        let kind = if section == "# Implicit Rules" || name.contains('%') {
+            MakeTargetKind::Pattern
+        } else if section == "# Files" {
+            if name.contains(['.', '/']) {
+                MakeTargetKind::File
+            } else {
+                MakeTargetKind::Target
+            }
+        } else {
+            continue;
+        };
+
+        targets.extend(current.replace(MakeTarget {
+            name: name.to_string(),
+            kind,
+        }));
+    }
+
+    targets.extend(current);
+    targets
+}
+
+#[test]
+fn test_parse_make_database() {
+    let database = r#"
+# Variables
+
+VERSION := 1
+
+# Implicit Rules
+
+%.o: %.c
+#  recipe to execute (from 'Makefile', line 9):
+	cc -c $<
+
+# Files
+
+out.txt: src.c
+#  Implicit rule search has not been done.
+#  recipe to execute (from 'Makefile', line 8):
+	cp src.c out.txt
+
+# Not a target:
+Makefile:
+#  Implicit rule search has been done.
+
+# Not a target:
+::
+#  Command line target.
+
+build: out.txt
+#  Phony target (prerequisite of .PHONY).
+#  recipe to execute (from 'Makefile', line 4):
+	echo build
+
+sub:
+#  recipe to execute (from 'Makefile', line 13):
+	$(MAKE) -C nowhere all
+
+.PHONY: build
+#  Implicit rule search has not been done.
+
+# Finished Make data base on Sun Oct 18 23:50:19 2026
+"#;
+
+    let targets = parse_make_database(database);
+
+    assert_eq!(
+        targets,
+        vec![
+            MakeTarget {
+                name: "%.o".to_string(),
+                kind: MakeTargetKind::Pattern
+            },
+            MakeTarget {
+                name: "out.txt".to_string(),
+                kind: MakeTargetKind::File
+            },
+            MakeTarget {
+                name: "build".to_string(),
+                kind: MakeTargetKind::Phony
+            },
+            MakeTarget {
+                name: "sub".to_string(),
+                kind: MakeTargetKind::Target
+            },
+        ]
+    );
+}
@@ -1,6 +1,82 @@
-#[derive(Debug, Clone)]
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+
+use crate::archetypes::ArchetypeRegistry;
+use crate::workspaces::WorkspaceSource;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
 pub struct Manifest {
+    /// Package globs relative to the project root, globs prefixed with `!` exclude packages
     pub packages: Vec<String>,
+    /// Workspace definitions to import additional package globs from
+    pub workspaces: Vec<WorkspaceSource>,
+    /// Runner preferences applied to every package
+    pub runners: RunnerPreferences,
+    /// Settings applied to packages of a given archetype, keyed by archetype id
+    pub archetypes: BTreeMap<String, ArchetypeSettings>,
+    /// Settings applied to packages whose path matches a glob, in declaration order
+    pub overrides: Vec<PackageOverride>,
+    /// Archetype plugin executables, paths relative to the project root or names on PATH
+    pub plugins: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ArchetypeSettings {
+    pub runners: RunnerPreferences,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct PackageOverride {
+    /// Glob matched against the package path relative to the project root
+    pub glob: String,
+    /// Archetype id used instead of auto-detection
+    pub archetype: Option<String>,
+    pub runners: RunnerPreferences,
+    /// Tags added to matching packages, for example to filter them
+    pub tags: Vec<String>,
+    /// Owners of matching packages, take precedence over `CODEOWNERS`
+    pub owners: Vec<String>,
+}
+
+/// Order in which script runners are consulted, and runners that should never be used.
+///
+/// Runners listed in `order` are consulted first, in that order, followed by the
+/// remaining runners in their default order.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct RunnerPreferences {
+    pub order: Option<Vec<String>>,
+    pub disabled: Vec<String>,
+}
+
+impl RunnerPreferences {
+    /// Layers more specific preferences on top: order is replaced, disabled runners accumulate
+    pub fn merge(&mut self, other: &RunnerPreferences) {
+        if other.order.is_some() {
+            self.order = other.order.clone();
+        }
+        self.disabled.extend(other.disabled.iter().cloned());
+    }
+}
+
+impl PackageOverride {
+    pub fn matches(&self, package_path: &str) -> bool {
+        matches_package_glob(&self.glob, package_path)
+    }
+}
+
+/// Match package path relative to the project root, `./` prefix of the glob is optional
+pub(crate) fn matches_package_glob(glob: &str, package_path: &str) -> bool {
+    let glob = glob.strip_prefix("./").unwrap_or(glob);
+
+    match glob::Pattern::new(glob) {
+        Ok(pattern) => pattern.matches_path(Path::new(package_path)),
+        Err(_) => false,
+    }
 }
 
 impl Default for Manifest {
@@ -13,6 +89,311 @@ impl Manifest {
     pub fn new() -> Manifest {
         Manifest {
             packages: Vec::from([String::from("./packages/*"), String::from("./apps/*")]),
+            workspaces: vec![],
+            runners: RunnerPreferences::default(),
+            archetypes: BTreeMap::new(),
+            overrides: vec![],
+            plugins: vec![],
+        }
+    }
+
+    pub fn from_path(manifest_path: &Path) -> Result<Manifest> {
+        let content = std::fs::read_to_string(manifest_path).with_context(|| {
+            format!(
+                "Failed to read manifest file at {}",
+                manifest_path.display()
+            )
+        })?;
+
+        Manifest::parse(&content).with_context(|| {
+            format!(
+                "Failed to parse manifest file at {}",
+                manifest_path.display()
+            )
+        })
+    }
+
+    pub fn parse(content: &str) -> Result<Manifest> {
+        // Empty manifest is valid and means defaults
+        if content.trim().is_empty() {
+            return Ok(Manifest::new());
+        }
+
+        Ok(serde_yaml_ng::from_str(content)?)
+    }
+
+    /// Check that archetype ids used by overrides and runner ids used by runner
+    /// preferences are known to the registry
+    pub fn validate(&self, registry: &ArchetypeRegistry) -> Result<()> {
+        for package_override in &self.overrides {
+            if let Some(archetype_id) = &package_override.archetype
+                && registry.get_archetype_by_id(archetype_id).is_none()
+            {
+                bail!(
+                    "Unknown archetype `{}` in override for `{}`, known archetypes: {}",
+                    archetype_id,
+                    package_override.glob,
+                    registry.get_ids().join(", ")
+                );
+            }
+        }
+
+        let runner_ids = registry.get_runner_ids();
+        let validate_runners = |preferences: &RunnerPreferences, scope: &str| -> Result<()> {
+            for runner_id in preferences
+                .order
+                .iter()
+                .flatten()
+                .chain(&preferences.disabled)
+            {
+                if !runner_ids.contains(runner_id) {
+                    bail!(
+                        "Unknown runner `{}` in {}, known runners: {}",
+                        runner_id,
+                        scope,
+                        runner_ids.join(", ")
+                    );
+                }
+            }
+            Ok(())
+        };
+
+        validate_runners(&self.runners, "`runners`")?;
+        for (archetype_id, settings) in &self.archetypes {
+            validate_runners(
+                &settings.runners,
+                &format!("runners of archetype `{}`", archetype_id),
+            )?;
+        }
+        for package_override in &self.overrides {
+            validate_runners(
+                &package_override.runners,
+                &format!("override for `{}`", package_override.glob),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Archetype set explicitly for a package, the last matching override wins
+    pub fn get_archetype_override(&self, package_path: &str) -> Option<&str> {
+        self.overrides
+            .iter()
+            .rev()
+            .filter(|package_override| package_override.matches(package_path))
+            .find_map(|package_override| package_override.archetype.as_deref())
+    }
+
+    /// Tags of every matching override, in declaration order
+    pub fn get_tags(&self, package_path: &str) -> Vec<String> {
+        let mut tags: Vec<String> = vec![];
+        for tag in self
+            .overrides
+            .iter()
+            .filter(|package_override| package_override.matches(package_path))
+            .flat_map(|package_override| &package_override.tags)
+        {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        tags
+    }
+
+    /// Owners set explicitly for a package, the last matching override with owners wins
+    pub fn get_owners(&self, package_path: &str) -> Option<&[String]> {
+        self.overrides
+            .iter()
+            .rev()
+            .find(|package_override| {
+                !package_override.owners.is_empty() && package_override.matches(package_path)
+            })
+            .map(|package_override| package_override.owners.as_slice())
+    }
+
+    /// Resolve runner preferences for a package: global, then archetype, then matching overrides
+    pub fn get_runner_preferences(
+        &self,
+        package_path: &str,
+        archetype_id: &str,
+    ) -> RunnerPreferences {
+        let mut preferences = self.runners.clone();
+
+        if let Some(archetype) = self.archetypes.get(archetype_id) {
+            preferences.merge(&archetype.runners);
         }
+
+        self.overrides
+            .iter()
+            .filter(|package_override| package_override.matches(package_path))
+            .for_each(|package_override| preferences.merge(&package_override.runners));
+
+        preferences
+    }
+}
+
+#[test]
+fn test_parse_empty_manifest() -> anyhow::Result<()> {
+    let manifest = Manifest::parse("")?;
+
+    assert_eq!(manifest.packages, Manifest::new().packages);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_runner_preferences() -> anyhow::Result<()> {
+    let manifest = Manifest::parse(
+        r#"
+runners:
+  disabled: [just]
+archetypes:
+  nodejs/npm:
+    runners:
+      order: [make, npm]
+overrides:
+  - glob: ./apps/*
+    runners:
+      order: [npm, make]
+"#,
+    )?;
+
+    let app = manifest.get_runner_preferences("apps/web", "nodejs/npm");
+    assert_eq!(app.order, Some(vec!["npm".to_string(), "make".to_string()]));
+    assert_eq!(app.disabled, vec!["just".to_string()]);
+
+    let lib = manifest.get_runner_preferences("packages/lib", "nodejs/npm");
+    assert_eq!(lib.order, Some(vec!["make".to_string(), "npm".to_string()]));
+
+    let python = manifest.get_runner_preferences("packages/py", "python/poetry");
+    assert_eq!(python.order, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_archetype_override() -> anyhow::Result<()> {
+    let manifest = Manifest::parse(
+        r#"
+overrides:
+  - glob: ./packages/*
+    archetype: python/pep621
+  - glob: ./packages/web
+    runners:
+      disabled: [make]
+  - glob: ./packages/tools
+    archetype: nodejs/npm
+"#,
+    )?;
+
+    assert_eq!(
+        manifest.get_archetype_override("packages/web"),
+        Some("python/pep621")
+    );
+    assert_eq!(
+        manifest.get_archetype_override("packages/tools"),
+        Some("nodejs/npm")
+    );
+    assert_eq!(manifest.get_archetype_override("apps/web"), None);
+
+    assert!(manifest.validate(&ArchetypeRegistry::builtin()).is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_unknown_archetype() -> anyhow::Result<()> {
+    let manifest = Manifest::parse(
+        r#"
+overrides:
+  - glob: ./packages/*
+    archetype: python/conda
+"#,
+    )?;
+
+    let err = manifest
+        .validate(&ArchetypeRegistry::builtin())
+        .unwrap_err();
+    assert!(err.to_string().contains("python/conda"));
+
+    Ok(())
+}
+
+#[test]
+fn test_validate_unknown_runner() -> anyhow::Result<()> {
+    let registry = ArchetypeRegistry::builtin();
+
+    let manifest = Manifest::parse(
+        r#"
+runners:
+  order: [just, make]
+archetypes:
+  nodejs/npm:
+    runners:
+      order: [pnpm]
+overrides:
+  - glob: ./packages/*
+    runners:
+      disabled: [mrt, uv]
+"#,
+    )?;
+    assert!(manifest.validate(&registry).is_ok());
+
+    let invalid_manifests = [
+        ("runners:\n  order: [makefile]", "in `runners`"),
+        (
+            "archetypes:\n  nodejs/npm:\n    runners:\n      disabled: [yarnpkg]",
+            "in runners of archetype `nodejs/npm`",
+        ),
+        (
+            "overrides:\n  - glob: ./apps/*\n    runners:\n      order: [gradlew]",
+            "in override for `./apps/*`",
+        ),
+    ];
+    for (content, scope) in invalid_manifests {
+        let err = Manifest::parse(content)?.validate(&registry).unwrap_err();
+        assert!(err.to_string().contains(scope), "{}", err);
+        assert!(
+            err.to_string().contains("known runners: bun, cmake"),
+            "{}",
+            err
+        );
     }
+
+    Ok(())
+}
+
+#[test]
+fn test_get_tags_and_owners() -> anyhow::Result<()> {
+    let manifest = Manifest::parse(
+        r#"
+overrides:
+  - glob: ./packages/*
+    tags: [library]
+    owners: ["@acme/platform"]
+  - glob: ./packages/billing
+    tags: [service, library]
+    owners: ["@acme/payments"]
+  - glob: ./packages/*
+    runners:
+      disabled: [make]
+"#,
+    )?;
+
+    assert_eq!(
+        manifest.get_tags("packages/billing"),
+        vec!["library", "service"]
+    );
+    assert_eq!(
+        manifest.get_owners("packages/billing"),
+        Some(&["@acme/payments".to_string()][..])
+    );
+    assert_eq!(
+        manifest.get_owners("packages/web"),
+        Some(&["@acme/platform".to_string()][..])
+    );
+    assert!(manifest.get_tags("apps/web").is_empty());
+    assert_eq!(manifest.get_owners("apps/web"), None);
+
+    Ok(())
 }
@@ -1,8 +1,9 @@
 use anyhow::{Context, Result};
 use serde::Deserialize;
-use std::{fs::File, io::BufReader, path::Path};
+use serde_json::Value;
+use std::{collections::BTreeMap, fs::File, io::BufReader, path::Path};
 
-use crate::package::PackageInfoExtractor;
+use crate::package::{PackageInfoExtractor, deserialize_lenient};
 
 pub struct NpmPackageInfoExtractor {
     npm_package: NpmPackage,
@@ -13,6 +14,39 @@ pub struct NpmPackageInfoExtractor {
 struct NpmPackage {
     name: String,
     version: String,
+    #[serde(default)]
+    scripts: BTreeMap<String, String>,
+    // Informational fields are optional, a value of wrong type is ignored
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    description: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    private: Option<bool>,
+    // Deprecated form is `{ "type": "MIT", "url": ... }`
+    license: Option<Value>,
+    // Either "Name <email> (url)" or `{ "name": ... }`
+    author: Option<Value>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    contributors: Option<Vec<Value>>,
+    // Either "github:user/repo" shorthand or `{ "type": "git", "url": ... }`
+    repository: Option<Value>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    keywords: Option<Vec<String>>,
+    #[serde(default)]
+    dependencies: BTreeMap<String, Value>,
+    #[serde(default, rename = "devDependencies")]
+    dev_dependencies: BTreeMap<String, Value>,
+    #[serde(default, rename = "peerDependencies")]
+    peer_dependencies: BTreeMap<String, Value>,
+    #[serde(default, rename = "optionalDependencies")]
+    optional_dependencies: BTreeMap<String, Value>,
+}
+
+fn person_name(person: &Value) -> Option<String> {
+    match person {
+        Value::String(person) => Some(person.clone()),
+        Value::Object(person) => person.get("name")?.as_str().map(str::to_string),
+        _ => None,
+    }
 }
 
 impl NpmPackageInfoExtractor {
@@ -38,6 +72,10 @@ impl NpmPackageInfoExtractor {
             npm_package: package_json,
         })
     }
+
+    pub fn has_script(&self, script_name: &str) -> bool {
+        self.npm_package.scripts.contains_key(script_name)
+    }
 }
 
 impl PackageInfoExtractor for NpmPackageInfoExtractor {
@@ -48,6 +86,60 @@ impl PackageInfoExtractor for NpmPackageInfoExtractor {
     fn get_version(&self) -> &str {
         self.npm_package.version.as_str()
     }
+
+    fn get_description(&self) -> Option<&str> {
+        self.npm_package.description.as_deref()
+    }
+
+    fn is_private(&self) -> Option<bool> {
+        self.npm_package.private
+    }
+
+    fn get_license(&self) -> Option<&str> {
+        match self.npm_package.license.as_ref()? {
+            Value::String(license) => Some(license.as_str()),
+            license => license.get("type")?.as_str(),
+        }
+    }
+
+    fn get_authors(&self) -> Vec<String> {
+        self.npm_package
+            .author
+            .iter()
+            .chain(self.npm_package.contributors.iter().flatten())
+            .filter_map(person_name)
+            .collect()
+    }
+
+    fn get_repository(&self) -> Option<&str> {
+        match self.npm_package.repository.as_ref()? {
+            Value::String(repository) => Some(repository.as_str()),
+            repository => repository.get("url")?.as_str(),
+        }
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        self.npm_package.keywords.clone().unwrap_or_default()
+    }
+
+    fn get_scripts(&self) -> Vec<String> {
+        self.npm_package.scripts.keys().cloned().collect()
+    }
+
+    fn get_dependencies(&self) -> Vec<String> {
+        let package = &self.npm_package;
+        let mut dependencies: Vec<String> = package
+            .dependencies
+            .keys()
+            .chain(package.dev_dependencies.keys())
+            .chain(package.peer_dependencies.keys())
+            .chain(package.optional_dependencies.keys())
+            .cloned()
+            .collect();
+        dependencies.sort();
+        dependencies.dedup();
+        dependencies
+    }
 }
 
 #[test]
@@ -80,3 +172,69 @@ fn test_from_package_path_success() {
 
     assert_eq!(result.npm_package.name, "node-lib1");
 }
+
+#[test]
+fn test_has_script() {
+    let lib1_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/packages/node-lib1");
+
+    let result = NpmPackageInfoExtractor::from_package_path(&lib1_path).unwrap();
+
+    assert!(result.has_script("build"));
+    assert!(!result.has_script("no-such-script"));
+}
+
+#[test]
+fn test_metadata() {
+    let package_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("./fixures/metadata-lib");
+
+    let result = NpmPackageInfoExtractor::from_package_path(&package_path).unwrap();
+
+    assert_eq!(result.get_description(), Some("Shared UI widgets"));
+    assert_eq!(result.is_private(), Some(true));
+    assert_eq!(result.get_license(), Some("MIT"));
+    assert_eq!(
+        result.get_authors(),
+        vec!["Jane Doe", "John Roe <john@example.com>"]
+    );
+    assert_eq!(
+        result.get_repository(),
+        Some("git+https://github.com/example/widgets.git")
+    );
+    assert_eq!(result.get_keywords(), vec!["ui", "widgets"]);
+    assert_eq!(result.get_scripts(), vec!["build", "test"]);
+    assert_eq!(
+        result.get_dependencies(),
+        vec!["@example/tokens", "react", "vitest"]
+    );
+}
+
+#[test]
+fn test_malformed_metadata() {
+    let package_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("./fixures/malformed-metadata-lib");
+
+    let result = NpmPackageInfoExtractor::from_package_path(&package_path).unwrap();
+
+    assert_eq!(result.get_name(), "@example/forms");
+    assert_eq!(result.get_description(), None);
+    assert_eq!(result.is_private(), None);
+    assert_eq!(result.get_authors(), vec!["Jane Doe"]);
+    assert!(result.get_keywords().is_empty());
+    assert_eq!(result.get_scripts(), vec!["build"]);
+}
+
+#[test]
+fn test_private_is_unknown_when_absent() {
+    let lib1_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/packages/node-lib1");
+
+    let result = NpmPackageInfoExtractor::from_package_path(&lib1_path).unwrap();
+
+    assert_eq!(result.is_private(), None);
+}
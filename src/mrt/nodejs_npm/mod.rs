@@ -2,11 +2,20 @@ use std::path::Path;
 
 use anyhow::{Context, Result};
 
-use crate::{archetypes::Archetype, package::PackageInfoExtractor, runners::WrapperScriptRunner};
-
-use self::{info::NpmPackageInfoExtractor, runner::NpmPackageScriptRunner};
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{
+    info::NpmPackageInfoExtractor, package_manager::NodePackageManager,
+    runner::NodePackageScriptRunner,
+};
 
 mod info;
+mod package_manager;
 mod runner;
 
 pub struct NodeJSNpmArchetype {}
@@ -20,10 +29,24 @@ impl Archetype for NodeJSNpmArchetype {
         package_path.join("package.json").exists()
     }
 
-    fn get_script_runner(&self) -> Box<dyn crate::runners::ScriptRunner> {
-        Box::from(WrapperScriptRunner::wrap_with_generic_runners(Box::from(
-            NpmPackageScriptRunner::new(),
-        )))
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        // Each package manager runner only accepts packages where it is detected
+        let runners: Vec<Box<dyn ScriptRunner>> = [
+            NodePackageManager::Npm,
+            NodePackageManager::Pnpm,
+            NodePackageManager::Yarn,
+            NodePackageManager::Bun,
+        ]
+        .into_iter()
+        .map(|package_manager| {
+            Box::new(NodePackageScriptRunner::new(package_manager)) as Box<dyn ScriptRunner>
+        })
+        .collect();
+
+        Box::from(
+            WrapperScriptRunner::wrap_all_with_generic_runners(runners)
+                .with_preferences(preferences),
+        )
     }
 
     fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
@@ -0,0 +1,156 @@
+use serde::Deserialize;
+use std::{fs::File, io::BufReader, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodePackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+// Only `packageManager` field of package.json, other fields may be missing in workspace roots
+#[derive(Deserialize, Debug)]
+struct PackageManagerField {
+    #[serde(rename = "packageManager")]
+    package_manager: Option<String>,
+}
+
+const LOCKFILES: [(&str, NodePackageManager); 5] = [
+    ("pnpm-lock.yaml", NodePackageManager::Pnpm),
+    ("yarn.lock", NodePackageManager::Yarn),
+    ("bun.lockb", NodePackageManager::Bun),
+    ("bun.lock", NodePackageManager::Bun),
+    ("package-lock.json", NodePackageManager::Npm),
+];
+
+impl NodePackageManager {
+    pub fn get_id(&self) -> &'static str {
+        match self {
+            NodePackageManager::Npm => "npm",
+            NodePackageManager::Pnpm => "pnpm",
+            NodePackageManager::Yarn => "yarn",
+            NodePackageManager::Bun => "bun",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<NodePackageManager> {
+        match id {
+            "npm" => Some(NodePackageManager::Npm),
+            "pnpm" => Some(NodePackageManager::Pnpm),
+            "yarn" => Some(NodePackageManager::Yarn),
+            "bun" => Some(NodePackageManager::Bun),
+            _ => None,
+        }
+    }
+
+    /// Detect package manager from `packageManager` field or lockfiles,
+    /// looking from the package directory up to the repository root.
+    /// Falls back to npm when nothing is found.
+    pub fn detect(package_path: &Path) -> NodePackageManager {
+        for path in package_path.ancestors() {
+            if let Some(package_manager) = Self::from_package_json(path) {
+                return package_manager;
+            }
+
+            if let Some((_, package_manager)) = LOCKFILES
+                .iter()
+                .find(|(lockfile, _)| path.join(lockfile).is_file())
+            {
+                return *package_manager;
+            }
+
+            if path.join(".git").exists() {
+                break;
+            }
+        }
+
+        NodePackageManager::Npm
+    }
+
+    fn from_package_json(path: &Path) -> Option<NodePackageManager> {
+        let package_json_file = File::open(path.join("package.json")).ok()?;
+        let field: PackageManagerField =
+            serde_json::from_reader(BufReader::new(package_json_file)).ok()?;
+
+        // Format is `<name>@<version>`, e.g. `pnpm@9.1.0+sha512...`
+        let package_manager = field.package_manager?;
+        let (name, _version) = package_manager.split_once('@')?;
+
+        Self::from_id(name)
+    }
+
+    /// Arguments to run a script, forwarding extra arguments the way each tool expects
+    pub fn get_run_args(&self, script_spec: &str, script_args: &[String]) -> Vec<String> {
+        let mut args = vec!["run".to_string(), script_spec.to_string()];
+
+        if !script_args.is_empty() {
+            // npm consumes options itself unless they are separated by `--`,
+            // pnpm, Yarn and Bun forward everything after the script name
+            if *self == NodePackageManager::Npm {
+                args.push("--".to_string());
+            }
+            args.extend(script_args.iter().cloned());
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+fn get_fixture_path(fixture: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("fixures")
+        .join(fixture)
+}
+
+#[test]
+fn test_detect_default_npm() {
+    let lib1_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/packages/node-lib1");
+
+    assert_eq!(
+        NodePackageManager::detect(&lib1_path),
+        NodePackageManager::Npm
+    );
+}
+
+#[test]
+fn test_detect_lockfile_up_the_tree() {
+    let lib_path = get_fixture_path("pnpm-workspace/packages/lib");
+
+    assert_eq!(
+        NodePackageManager::detect(&lib_path),
+        NodePackageManager::Pnpm
+    );
+}
+
+#[test]
+fn test_detect_package_manager_field() {
+    let lib_path = get_fixture_path("yarn-package-manager");
+
+    assert_eq!(
+        NodePackageManager::detect(&lib_path),
+        NodePackageManager::Yarn
+    );
+}
+
+#[test]
+fn test_get_run_args() {
+    let script_args = vec!["--watch".to_string()];
+
+    assert_eq!(
+        NodePackageManager::Npm.get_run_args("test", &script_args),
+        vec!["run", "test", "--", "--watch"]
+    );
+    assert_eq!(
+        NodePackageManager::Pnpm.get_run_args("test", &script_args),
+        vec!["run", "test", "--watch"]
+    );
+    assert_eq!(
+        NodePackageManager::Yarn.get_run_args("test", &[]),
+        vec!["run", "test"]
+    );
+}
@@ -1,44 +1,94 @@
 use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
-use anyhow::{Context, Result};
+use anyhow::Result;
 
-pub struct NpmPackageScriptRunner {
-    npm_runner: CommandRunner,
+use super::{info::NpmPackageInfoExtractor, package_manager::NodePackageManager};
+
+/// Runs package.json scripts with one package manager,
+/// only for packages where that package manager is detected
+pub struct NodePackageScriptRunner {
+    package_manager: NodePackageManager,
+    runner: CommandRunner,
 }
 
-impl NpmPackageScriptRunner {
-    pub fn new() -> NpmPackageScriptRunner {
-        NpmPackageScriptRunner {
-            npm_runner: CommandRunner::new("npm".to_string()),
+impl NodePackageScriptRunner {
+    pub fn new(package_manager: NodePackageManager) -> NodePackageScriptRunner {
+        NodePackageScriptRunner {
+            package_manager,
+            runner: CommandRunner::new(package_manager.get_id().to_string()),
         }
     }
+}
 
-    fn list_available_scripts(&self, context: &ScriptRunContext) -> Result<Vec<(String, String)>> {
-        let json = self
-            .npm_runner
-            .exec_command_json(vec!["run".to_string(), "--json".to_string()], context)?;
-
-        Ok(json
-            .as_object()
-            .context("`npm run --json` did not return an object")?
-            .iter()
-            .map(|(key, value)| (key.to_string(), value.to_string()))
-            .collect())
+impl ScriptRunner for NodePackageScriptRunner {
+    fn get_id(&self) -> &str {
+        self.package_manager.get_id()
     }
-}
 
-impl ScriptRunner for NpmPackageScriptRunner {
     fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
-        self.npm_runner.run_script(
-            vec!["run".to_string(), context.script_spec.to_string()],
+        self.runner.run_script(
+            self.package_manager
+                .get_run_args(context.script_spec, context.script_args),
             context,
         )
     }
 
     fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
-        let available_scripts = self.list_available_scripts(context)?;
+        let package_path = &context.package.absolute_path;
+
+        if NodePackageManager::detect(package_path) != self.package_manager {
+            return Ok(false);
+        }
+
+        // Read scripts from package.json directly, package manager CLI is only needed to execute them
+        let info = NpmPackageInfoExtractor::from_package_path(package_path)?;
 
-        Ok(available_scripts
-            .iter()
-            .any(|(key, _value)| key == context.script_spec))
+        Ok(info.has_script(context.script_spec))
     }
 }
+
+#[test]
+fn test_can_run_script_from_package_json() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/node-lib1"))?;
+    let runner = NodePackageScriptRunner::new(NodePackageManager::Npm);
+
+    let context = ScriptRunContext {
+        script_spec: "build",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+    assert!(runner.can_run_script(&context)?);
+
+    let context = ScriptRunContext {
+        script_spec: "no-such-script",
+        script_args: &[],
+        ..context
+    };
+    assert!(!runner.can_run_script(&context)?);
+
+    Ok(())
+}
+
+#[test]
+fn test_can_run_script_other_package_manager() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/node-lib1"))?;
+    let runner = NodePackageScriptRunner::new(NodePackageManager::Pnpm);
+
+    let context = ScriptRunContext {
+        script_spec: "build",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+    assert!(!runner.can_run_script(&context)?);
+
+    Ok(())
+}
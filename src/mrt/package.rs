@@ -1,11 +1,15 @@
 use anyhow::Result;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de::IgnoredAny};
 use std::{
     fmt::{Display, Formatter},
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
-use crate::archetypes::detect_archetype;
+use crate::archetypes::ArchetypeRegistry;
+use crate::manifest::Manifest;
+
+/// Version reported for packages that compute their version at build time
+pub const DYNAMIC_VERSION: &str = "dynamic";
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Package {
@@ -13,15 +17,100 @@ pub struct Package {
     pub version: String,
     pub path: String,
     pub absolute_path: PathBuf,
+    /// Primary archetype, package name and version come from it
     pub archetype_id: String,
+    /// Every archetype detected in the package directory, primary one first
+    #[serde(default)]
+    pub archetype_ids: Vec<String>,
+    /// Optional details declared by the package manifest
+    #[serde(default)]
+    pub metadata: PackageMetadata,
+    /// Tags from manifest overrides
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Owners from manifest overrides, or from `CODEOWNERS`
+    #[serde(default)]
+    pub owners: Vec<String>,
     pub status: PackageStatus,
 }
 
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PackageMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `true` when the package is not meant to be published, `None` when unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authors: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub keywords: Vec<String>,
+    /// Scripts declared in the package manifest, generic runners are not included
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scripts: Vec<String>,
+    /// Names of declared dependencies of any kind, including ones outside the repository
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Lenient<T> {
+    Valid(T),
+    Invalid(IgnoredAny),
+}
+
+/// Reads an optional manifest field, a value of unexpected type is read as missing.
+///
+/// Use with `#[serde(default, deserialize_with = "deserialize_lenient")]`, so a typo in
+/// informational metadata like `"keywords": "ui"` does not make the package unreadable.
+pub(crate) fn deserialize_lenient<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(match Option::<Lenient<T>>::deserialize(deserializer)? {
+        Some(Lenient::Valid(value)) => Some(value),
+        Some(Lenient::Invalid(_)) | None => None,
+    })
+}
+
+impl PackageMetadata {
+    pub fn from_extractor(extractor: &dyn PackageInfoExtractor) -> PackageMetadata {
+        let metadata = extractor.get_metadata();
+
+        // Manifests often keep empty placeholders, like `"description": ""`
+        let non_empty = |value: Option<String>| {
+            value
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        PackageMetadata {
+            description: non_empty(metadata.description),
+            license: non_empty(metadata.license),
+            authors: metadata
+                .authors
+                .into_iter()
+                .filter(|author| !author.trim().is_empty())
+                .collect(),
+            repository: non_empty(metadata.repository),
+            ..metadata
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PackageStatus {
     Valid,
     CannotRead(String),
     CannotDetectArchetype,
+    /// Package can be used, but depends on itself through other packages
+    DependencyCycle(String),
 }
 
 impl Display for PackageStatus {
@@ -30,12 +119,20 @@ impl Display for PackageStatus {
             PackageStatus::Valid => write!(f, "Valid"),
             PackageStatus::CannotRead(message) => write!(f, "Error: {}", message),
             PackageStatus::CannotDetectArchetype => write!(f, "Cannot detect archetype"),
+            PackageStatus::DependencyCycle(cycle) => {
+                write!(f, "Warning: dependency cycle {}", cycle)
+            }
         }
     }
 }
 
 impl Package {
-    pub fn from_package_path(package_path: PathBuf, project_path: PathBuf) -> Result<Package> {
+    pub fn from_package_path(
+        package_path: PathBuf,
+        project_path: PathBuf,
+        registry: &ArchetypeRegistry,
+        manifest: &Manifest,
+    ) -> Result<Package> {
         let absolute_path = package_path.canonicalize()?;
 
         let path = absolute_path
@@ -44,45 +141,144 @@ impl Package {
             .unwrap_or("n/a")
             .to_string();
 
-        match detect_archetype(&absolute_path) {
-            Some(archetype) => {
-                match archetype.get_info_extractor(&absolute_path) {
-                    Ok(extractor) => Ok(Package {
-                        name: extractor.get_name().to_string(),
-                        version: extractor.get_version().to_string(),
-                        path,
-                        absolute_path,
-                        archetype_id: archetype.get_id().to_string(),
-                        status: PackageStatus::Valid,
-                    }),
-                    Err(err) => {
-                        // Archetype detected, but cannot read package info
-                        Ok(Package {
-                            name: String::from("n/a"),
-                            version: String::from("n/a"),
-                            path,
-                            absolute_path,
-                            archetype_id: archetype.get_id().to_string(),
-                            status: PackageStatus::CannotRead(err.to_string()),
-                        })
-                    }
-                }
-            }
-            None => Ok(Package {
-                name: String::from("n/a"),
-                version: String::from("n/a"),
-                path,
-                absolute_path,
-                archetype_id: String::default(),
-                status: PackageStatus::CannotDetectArchetype,
-            }),
+        // Directory may be, for example, both npm and Python package,
+        // unless the manifest sets the archetype explicitly
+        let archetypes = match manifest.get_archetype_override(&path) {
+            Some(archetype_id) => registry
+                .get_archetype_by_id(archetype_id)
+                .into_iter()
+                .collect(),
+            None => registry.detect_archetypes(&absolute_path),
+        };
+        let archetype_ids: Vec<String> = archetypes
+            .iter()
+            .map(|archetype| archetype.get_id().to_string())
+            .collect();
+
+        let (name, version, archetype_id, metadata, status) = match archetypes.first() {
+            Some(archetype) => match archetype.get_info_extractor(&absolute_path) {
+                Ok(extractor) => (
+                    extractor.get_name().to_string(),
+                    extractor.get_version().to_string(),
+                    archetype.get_id().to_string(),
+                    PackageMetadata::from_extractor(extractor.as_ref()),
+                    PackageStatus::Valid,
+                ),
+                // Archetype detected, but cannot read package info
+                Err(err) => (
+                    String::from("n/a"),
+                    String::from("n/a"),
+                    archetype.get_id().to_string(),
+                    PackageMetadata::default(),
+                    PackageStatus::CannotRead(err.to_string()),
+                ),
+            },
+            None => (
+                String::from("n/a"),
+                String::from("n/a"),
+                String::default(),
+                PackageMetadata::default(),
+                PackageStatus::CannotDetectArchetype,
+            ),
+        };
+
+        Ok(Package {
+            name,
+            version,
+            archetype_id,
+            archetype_ids,
+            metadata,
+            tags: manifest.get_tags(&path),
+            // Owners from `CODEOWNERS` are resolved by the project
+            owners: manifest
+                .get_owners(&path)
+                .map(<[String]>::to_vec)
+                .unwrap_or_default(),
+            path,
+            absolute_path,
+            status,
+        })
+    }
+
+    /// Match package name, or path relative to the project root like `./packages/web/`
+    pub fn matches_name_or_path(&self, name_or_path: &str) -> bool {
+        let path = name_or_path.strip_prefix("./").unwrap_or(name_or_path);
+
+        self.name == name_or_path || self.path == path.trim_end_matches(['/', '\\'])
+    }
+}
+
+/// Find a file in the package directory or its parents, up to the repository root.
+/// Useful for files shared by a workspace, like lockfiles or build tool wrappers
+pub(crate) fn find_in_ancestors(package_path: &Path, file_name: &str) -> Option<PathBuf> {
+    for path in package_path.ancestors() {
+        let candidate = path.join(file_name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+        if path.join(".git").exists() {
+            break;
         }
     }
+
+    None
 }
 
+/// Reads package details from its manifest. Only name and version are required,
+/// other details default to unknown
 pub trait PackageInfoExtractor {
     fn get_name(&self) -> &str;
     fn get_version(&self) -> &str;
+
+    fn get_description(&self) -> Option<&str> {
+        None
+    }
+
+    /// `Some(true)` when the package is not meant to be published
+    fn is_private(&self) -> Option<bool> {
+        None
+    }
+
+    /// SPDX expression or license name
+    fn get_license(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_authors(&self) -> Vec<String> {
+        vec![]
+    }
+
+    fn get_repository(&self) -> Option<&str> {
+        None
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Names of scripts declared in the package manifest
+    fn get_scripts(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Names of declared dependencies, as other packages would report them in `get_name`
+    fn get_dependencies(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Every optional detail at once, extractors that read them together may override it
+    fn get_metadata(&self) -> PackageMetadata {
+        PackageMetadata {
+            description: self.get_description().map(str::to_string),
+            private: self.is_private(),
+            license: self.get_license().map(str::to_string),
+            authors: self.get_authors(),
+            repository: self.get_repository().map(str::to_string),
+            keywords: self.get_keywords(),
+            scripts: self.get_scripts(),
+            dependencies: self.get_dependencies(),
+        }
+    }
 }
 
 pub struct NoopPackageInfoExtractor {}
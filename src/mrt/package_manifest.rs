@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+pub(crate) const PACKAGE_MANIFEST_FILE_NAME: &str = ".mrt.yml";
+
+/// Package level `.mrt.yml`, scripts are shell commands that override archetype defaults
+#[derive(Deserialize, Debug, Default)]
+#[serde(default)]
+pub(crate) struct PackageManifest {
+    pub scripts: BTreeMap<String, String>,
+}
+
+impl PackageManifest {
+    /// Read `.mrt.yml` of the package, missing file means empty manifest
+    pub fn from_package_path(package_path: &Path) -> Result<PackageManifest> {
+        let manifest_path = package_path.join(PACKAGE_MANIFEST_FILE_NAME);
+
+        if !manifest_path.is_file() {
+            return Ok(PackageManifest::default());
+        }
+
+        let content = std::fs::read_to_string(&manifest_path).with_context(|| {
+            format!(
+                "Failed to read package manifest at {}",
+                manifest_path.display()
+            )
+        })?;
+
+        if content.trim().is_empty() {
+            return Ok(PackageManifest::default());
+        }
+
+        serde_yaml_ng::from_str(&content).with_context(|| {
+            format!(
+                "Failed to parse package manifest at {}",
+                manifest_path.display()
+            )
+        })
+    }
+}
+
+/// Runs scripts declared in package `.mrt.yml` through the system shell
+pub struct PackageManifestScriptRunner {
+    shell_runner: CommandRunner,
+}
+
+impl Default for PackageManifestScriptRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PackageManifestScriptRunner {
+    pub fn new() -> Self {
+        let shell = if cfg!(windows) { "cmd" } else { "sh" };
+
+        Self {
+            shell_runner: CommandRunner::new(shell.to_string()),
+        }
+    }
+
+    fn get_shell_args(command: &str, script_args: &[String]) -> Vec<String> {
+        if cfg!(windows) {
+            let mut args = vec!["/C".to_string(), command.to_string()];
+            args.extend(script_args.iter().cloned());
+            args
+        } else {
+            // Positional parameters keep forwarded arguments quoted as they were passed
+            let mut args = vec![
+                "-c".to_string(),
+                format!("{} \"$@\"", command),
+                "mrt".to_string(),
+            ];
+            args.extend(script_args.iter().cloned());
+            args
+        }
+    }
+}
+
+impl ScriptRunner for PackageManifestScriptRunner {
+    fn get_id(&self) -> &str {
+        "mrt"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let manifest = PackageManifest::from_package_path(&context.package.absolute_path)?;
+
+        match manifest.scripts.get(context.script_spec) {
+            Some(command) => self
+                .shell_runner
+                .run_script(Self::get_shell_args(command, context.script_args), context),
+            None => Ok(ScriptRunResult::noop()),
+        }
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        let manifest = PackageManifest::from_package_path(&context.package.absolute_path)?;
+
+        Ok(manifest.scripts.contains_key(context.script_spec))
+    }
+}
+
+#[test]
+fn test_from_package_path() -> anyhow::Result<()> {
+    let lib4_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/packages/mrt-lib4");
+
+    let manifest = PackageManifest::from_package_path(&lib4_path)?;
+
+    assert!(manifest.scripts.contains_key("format"));
+
+    Ok(())
+}
+
+#[test]
+fn test_from_package_path_no_manifest() -> anyhow::Result<()> {
+    let lib1_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/packages/node-lib1");
+
+    let manifest = PackageManifest::from_package_path(&lib1_path)?;
+
+    assert!(manifest.scripts.is_empty());
+
+    Ok(())
+}
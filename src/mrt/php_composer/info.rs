@@ -0,0 +1,152 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::{collections::BTreeMap, fs::File, io::BufReader, path::Path};
+
+use crate::package::PackageInfoExtractor;
+
+pub struct ComposerPackageInfoExtractor {
+    composer_package: ComposerPackage,
+}
+
+// Structure represents composer.json info, name is only required for published packages
+// and version is usually taken from VCS tags
+#[derive(Deserialize, Debug)]
+struct ComposerPackage {
+    name: Option<String>,
+    version: Option<String>,
+    #[serde(default)]
+    scripts: BTreeMap<String, Value>,
+    description: Option<String>,
+    // Single identifier or an array of alternatives
+    license: Option<Value>,
+    #[serde(default)]
+    authors: Vec<ComposerAuthor>,
+    #[serde(default)]
+    keywords: Vec<String>,
+    #[serde(default)]
+    support: BTreeMap<String, Value>,
+    #[serde(default)]
+    require: BTreeMap<String, Value>,
+    #[serde(default, rename = "require-dev")]
+    require_dev: BTreeMap<String, Value>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ComposerAuthor {
+    name: Option<String>,
+}
+
+impl ComposerPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<ComposerPackageInfoExtractor> {
+        let composer_json_path = package_path.join("composer.json");
+
+        let composer_json_file = File::open(&composer_json_path).with_context(|| {
+            format!(
+                "Failed to open composer.json file at {}",
+                composer_json_path.display()
+            )
+        })?;
+
+        let composer_package = serde_json::from_reader(BufReader::new(composer_json_file))
+            .with_context(|| {
+                format!(
+                    "Failed to parse JSON of composer.json file at {}",
+                    composer_json_path.display()
+                )
+            })?;
+
+        Ok(ComposerPackageInfoExtractor { composer_package })
+    }
+
+    pub fn has_script(&self, script_name: &str) -> bool {
+        self.composer_package.scripts.contains_key(script_name)
+    }
+}
+
+impl PackageInfoExtractor for ComposerPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.composer_package.name.as_deref().unwrap_or("n/a")
+    }
+
+    fn get_version(&self) -> &str {
+        self.composer_package.version.as_deref().unwrap_or("n/a")
+    }
+
+    fn get_description(&self) -> Option<&str> {
+        self.composer_package.description.as_deref()
+    }
+
+    fn get_license(&self) -> Option<&str> {
+        match self.composer_package.license.as_ref()? {
+            Value::String(license) => Some(license.as_str()),
+            // Any of the listed licenses can be chosen, the first one is reported
+            license => license.get(0)?.as_str(),
+        }
+    }
+
+    fn get_authors(&self) -> Vec<String> {
+        self.composer_package
+            .authors
+            .iter()
+            .filter_map(|author| author.name.clone())
+            .collect()
+    }
+
+    fn get_repository(&self) -> Option<&str> {
+        self.composer_package.support.get("source")?.as_str()
+    }
+
+    fn get_keywords(&self) -> Vec<String> {
+        self.composer_package.keywords.clone()
+    }
+
+    fn get_scripts(&self) -> Vec<String> {
+        self.composer_package.scripts.keys().cloned().collect()
+    }
+
+    fn get_dependencies(&self) -> Vec<String> {
+        self.composer_package
+            .require
+            .keys()
+            .chain(self.composer_package.require_dev.keys())
+            // Platform requirements, like `php` or `ext-json`, are not packages
+            .filter(|name| name.contains('/'))
+            .cloned()
+            .collect()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = ComposerPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_library() {
+    let lib_path =
+        crate::testing::utils::get_repo_root().join("./references/php-sample/packages/http-client");
+    let result = ComposerPackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "acme/http-client");
+    assert_eq!(result.get_version(), "2.3.0");
+    assert!(result.has_script("lint"));
+    assert_eq!(result.get_description(), Some("Small HTTP client"));
+    assert_eq!(result.get_license(), Some("MIT"));
+    assert_eq!(result.get_authors(), vec!["Jane Doe"]);
+    assert_eq!(
+        result.get_repository(),
+        Some("https://github.com/acme/http-client")
+    );
+}
+
+#[test]
+fn test_from_package_path_project() {
+    let app_path =
+        crate::testing::utils::get_repo_root().join("./references/php-sample/packages/admin");
+    let result = ComposerPackageInfoExtractor::from_package_path(&app_path).unwrap();
+    assert_eq!(result.get_name(), "n/a");
+    assert_eq!(result.get_version(), "n/a");
+    assert!(result.has_script("serve"));
+}
@@ -0,0 +1,44 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{info::ComposerPackageInfoExtractor, runner::ComposerScriptRunner};
+
+mod info;
+mod runner;
+
+pub struct PhpComposerArchetype {}
+
+impl Archetype for PhpComposerArchetype {
+    fn get_id(&self) -> &str {
+        "php/composer"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join("composer.json").exists()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(ComposerScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            ComposerPackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
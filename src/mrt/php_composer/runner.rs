@@ -0,0 +1,40 @@
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+use anyhow::Result;
+
+use super::info::ComposerPackageInfoExtractor;
+
+/// Runs `scripts` of composer.json with `composer run-script`
+pub struct ComposerScriptRunner {
+    composer_runner: CommandRunner,
+}
+
+impl ComposerScriptRunner {
+    pub fn new() -> ComposerScriptRunner {
+        ComposerScriptRunner {
+            composer_runner: CommandRunner::new("composer".to_string()),
+        }
+    }
+}
+
+impl ScriptRunner for ComposerScriptRunner {
+    fn get_id(&self) -> &str {
+        "composer"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let mut args = vec!["run-script".to_string(), context.script_spec.to_string()];
+        // Composer consumes options itself unless they are separated by `--`
+        if !context.script_args.is_empty() {
+            args.push("--".to_string());
+            args.extend(context.script_args.iter().cloned());
+        }
+
+        self.composer_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        let info = ComposerPackageInfoExtractor::from_package_path(&context.package.absolute_path)?;
+
+        Ok(info.has_script(context.script_spec))
+    }
+}
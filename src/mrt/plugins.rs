@@ -0,0 +1,527 @@
+use anyhow::{Context, Result, bail};
+use log::warn;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::{
+    collections::HashMap,
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::{Arc, Mutex, PoisonError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    archetypes::{Archetype, ArchetypeRegistry},
+    manifest::RunnerPreferences,
+    package::{PackageInfoExtractor, PackageMetadata},
+    runners::{
+        CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner, WrapperScriptRunner,
+    },
+};
+
+/// Executables with this prefix on PATH are loaded as archetype plugins
+pub const PLUGIN_PREFIX: &str = "mrt-archetype-";
+
+/// How long a plugin may take to answer `detect`, `info`, or `list-scripts`
+pub const PLUGIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Archetype implemented by an external executable.
+///
+/// The executable is called with a command (`detect`, `info`, `list-scripts`, or `run`)
+/// as the only argument and a JSON request on standard input. All commands except `run`
+/// answer with a JSON response on standard output, `run` executes the script and its
+/// exit code is the result.
+///
+/// `detect` answers are remembered per package directory, so a plugin is asked once
+/// for each directory while the project is loaded.
+#[derive(Debug, Clone)]
+pub struct ArchetypePlugin {
+    id: String,
+    program: PathBuf,
+    timeout: Duration,
+    detected: Arc<Mutex<HashMap<PathBuf, bool>>>,
+}
+
+/// Plugins found by [`discover_plugins`], by where they were found
+#[derive(Debug, Default)]
+pub struct DiscoveredPlugins {
+    /// Plugins listed under `plugins` in the manifest, in manifest order
+    pub manifest: Vec<ArchetypePlugin>,
+    /// `mrt-archetype-*` executables found on PATH
+    pub path: Vec<ArchetypePlugin>,
+}
+
+#[derive(Serialize, Debug)]
+struct PluginRequest<'a> {
+    package_path: &'a Path,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    args: Option<&'a [String]>,
+}
+
+#[derive(Deserialize, Debug)]
+struct DetectResponse {
+    detected: bool,
+}
+
+#[derive(Deserialize, Debug)]
+struct InfoResponse {
+    name: String,
+    version: Option<String>,
+    // Optional `description`, `private`, `license`, and other metadata fields
+    #[serde(flatten)]
+    metadata: PackageMetadata,
+}
+
+#[derive(Deserialize, Debug)]
+struct ListScriptsResponse {
+    scripts: Vec<String>,
+}
+
+impl<'a> PluginRequest<'a> {
+    fn new(package_path: &'a Path) -> Self {
+        Self {
+            package_path,
+            script: None,
+            args: None,
+        }
+    }
+}
+
+impl ArchetypePlugin {
+    /// Archetype id is the executable name without `mrt-archetype-` prefix
+    pub fn from_program(program: PathBuf) -> Result<ArchetypePlugin> {
+        let file_name = program
+            .file_stem()
+            .map(|name| name.to_string_lossy().to_string())
+            .with_context(|| format!("Plugin path {} has no file name", program.display()))?;
+
+        let id = file_name
+            .strip_prefix(PLUGIN_PREFIX)
+            .unwrap_or(&file_name)
+            .to_string();
+
+        Ok(ArchetypePlugin {
+            id,
+            program,
+            timeout: PLUGIN_TIMEOUT,
+            detected: Arc::default(),
+        })
+    }
+
+    /// Overrides [`PLUGIN_TIMEOUT`] for this plugin
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        Self { timeout, ..self }
+    }
+
+    fn request<T: DeserializeOwned>(&self, command: &str, request: &PluginRequest) -> Result<T> {
+        let mut child = Command::new(&self.program)
+            .arg(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to start plugin {}", self.program.display()))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(serde_json::to_string(request)?.as_bytes())?;
+        }
+
+        // Pipes are drained while waiting, so a chatty plugin cannot block on a full pipe
+        let stdout = read_in_background(child.stdout.take());
+        let stderr = read_in_background(child.stderr.take());
+
+        let deadline = Instant::now() + self.timeout;
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                bail!(
+                    "Plugin {} did not answer `{}` within {} seconds",
+                    self.program.display(),
+                    command,
+                    self.timeout.as_secs_f32()
+                );
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+
+        let stdout = stdout.join().unwrap_or_default();
+        if !status.success() {
+            bail!(
+                "Plugin {} failed on `{}`: {}",
+                self.program.display(),
+                command,
+                String::from_utf8_lossy(&stderr.join().unwrap_or_default()).trim()
+            );
+        }
+
+        serde_json::from_slice(&stdout).with_context(|| {
+            format!(
+                "Failed to parse `{}` response of plugin {}",
+                command,
+                self.program.display()
+            )
+        })
+    }
+}
+
+impl DiscoveredPlugins {
+    /// Manifest plugins are chosen for the project, so they are matched before built-in
+    /// archetypes. PATH plugins are installed machine-wide and are matched after them.
+    pub fn register(self, registry: &mut ArchetypeRegistry) {
+        for plugin in self.manifest.into_iter().rev() {
+            registry.register_first(Box::new(plugin));
+        }
+        for plugin in self.path {
+            registry.register(Box::new(plugin));
+        }
+    }
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = vec![];
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
+
+/// Plugins listed in the manifest and `mrt-archetype-*` executables found on PATH.
+///
+/// Manifest entries containing a path separator are relative to the project root,
+/// other entries are looked up on PATH. Plugins with the same id are loaded once,
+/// a manifest entry wins over a PATH executable.
+pub fn discover_plugins(root_path: &Path, manifest_plugins: &[String]) -> DiscoveredPlugins {
+    let path_dirs: Vec<PathBuf> = std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).collect())
+        .unwrap_or_default();
+
+    let manifest_programs = manifest_plugins.iter().filter_map(|plugin| {
+        let program = if plugin.contains(['/', '\\']) {
+            let relative_path = plugin.strip_prefix("./").unwrap_or(plugin);
+            Some(root_path.join(relative_path)).filter(|program| is_executable(program))
+        } else {
+            path_dirs
+                .iter()
+                .flat_map(|dir| executable_names(plugin).map(move |name| dir.join(name)))
+                .find(|program| is_executable(program))
+        };
+
+        if program.is_none() {
+            warn!("Cannot find archetype plugin `{}`", plugin);
+        }
+        program
+    });
+
+    let path_programs = path_dirs.iter().flat_map(|dir| {
+        let mut programs: Vec<PathBuf> = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with(PLUGIN_PREFIX))
+                    && is_executable(path)
+            })
+            .collect();
+        programs.sort();
+        programs
+    });
+
+    let mut plugins = DiscoveredPlugins::default();
+    let programs = manifest_programs
+        .map(|program| (program, true))
+        .chain(path_programs.map(|program| (program, false)));
+    for (program, listed) in programs {
+        match ArchetypePlugin::from_program(program) {
+            Ok(plugin)
+                if !plugins
+                    .manifest
+                    .iter()
+                    .chain(&plugins.path)
+                    .any(|existing| existing.id == plugin.id) =>
+            {
+                match listed {
+                    true => plugins.manifest.push(plugin),
+                    false => plugins.path.push(plugin),
+                }
+            }
+            Ok(_) => {}
+            Err(err) => warn!("Cannot load archetype plugin: {:#}", err),
+        }
+    }
+    plugins
+}
+
+fn executable_names(name: &str) -> impl Iterator<Item = String> + '_ {
+    let extensions: &[&str] = if cfg!(windows) {
+        &["", ".exe", ".cmd", ".bat"]
+    } else {
+        &[""]
+    };
+
+    extensions
+        .iter()
+        .map(move |extension| format!("{}{}", name, extension))
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+impl Archetype for ArchetypePlugin {
+    fn get_id(&self) -> &str {
+        &self.id
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        let detected = self.detected.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(detected) = detected.get(package_path) {
+            return *detected;
+        }
+        drop(detected);
+
+        // A failing plugin is remembered as not detected, so it is not retried for the directory
+        let detected =
+            match self.request::<DetectResponse>("detect", &PluginRequest::new(package_path)) {
+                Ok(response) => response.detected,
+                Err(err) => {
+                    warn!("{:#}", err);
+                    false
+                }
+            };
+
+        self.detected
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(package_path.to_path_buf(), detected);
+        detected
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(PluginScriptRunner {
+                plugin: self.clone(),
+            }))
+            .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let response: InfoResponse = self
+            .request("info", &PluginRequest::new(package_path))
+            .context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(PluginPackageInfoExtractor {
+            name: response.name,
+            version: response.version.unwrap_or_else(|| String::from("n/a")),
+            metadata: response.metadata,
+        }))
+    }
+}
+
+struct PluginPackageInfoExtractor {
+    name: String,
+    version: String,
+    metadata: PackageMetadata,
+}
+
+impl PackageInfoExtractor for PluginPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    fn get_metadata(&self) -> PackageMetadata {
+        self.metadata.clone()
+    }
+}
+
+/// Runs scripts listed by the plugin, runner id is the plugin id
+struct PluginScriptRunner {
+    plugin: ArchetypePlugin,
+}
+
+impl ScriptRunner for PluginScriptRunner {
+    fn get_id(&self) -> &str {
+        &self.plugin.id
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let request = PluginRequest {
+            package_path: &context.package.absolute_path,
+            script: Some(context.script_spec),
+            args: Some(context.script_args),
+        };
+
+        CommandRunner::new(self.plugin.program.to_string_lossy().to_string()).run_script_with_input(
+            vec!["run".to_string()],
+            Some(&serde_json::to_string(&request)?),
+            context,
+        )
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        let response: ListScriptsResponse = self.plugin.request(
+            "list-scripts",
+            &PluginRequest::new(&context.package.absolute_path),
+        )?;
+
+        Ok(response
+            .scripts
+            .iter()
+            .any(|script| script == context.script_spec))
+    }
+}
+
+#[cfg(test)]
+fn get_sample_path() -> PathBuf {
+    crate::testing::utils::get_repo_root().join("./references/plugins-sample")
+}
+
+#[test]
+fn test_from_program() -> anyhow::Result<()> {
+    let plugin = ArchetypePlugin::from_program(PathBuf::from("/usr/bin/mrt-archetype-bazel"))?;
+
+    assert_eq!(plugin.get_id(), "bazel");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_discover_plugins_from_manifest() {
+    let plugins = discover_plugins(
+        &get_sample_path(),
+        &[
+            "./tools/mrt-archetype-acme".to_string(),
+            "./tools/mrt-archetype-acme".to_string(),
+            "./tools/does-not-exist".to_string(),
+        ],
+    );
+
+    assert_eq!(plugins.manifest.len(), 1);
+    assert_eq!(plugins.manifest[0].get_id(), "acme");
+    assert!(plugins.path.iter().all(|plugin| plugin.get_id() != "acme"));
+}
+
+#[test]
+fn test_register_discovered_plugins() -> anyhow::Result<()> {
+    let mut registry = ArchetypeRegistry::builtin();
+    DiscoveredPlugins {
+        manifest: vec![
+            ArchetypePlugin::from_program(PathBuf::from("./tools/mrt-archetype-acme"))?,
+            ArchetypePlugin::from_program(PathBuf::from("./tools/mrt-archetype-zeta"))?,
+        ],
+        path: vec![ArchetypePlugin::from_program(PathBuf::from(
+            "/usr/bin/mrt-archetype-bazel",
+        ))?],
+    }
+    .register(&mut registry);
+
+    let ids = registry.get_ids();
+    assert_eq!(ids[..2], ["acme", "zeta"]);
+    assert_eq!(ids.last(), Some(&"bazel"));
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_plugin_detect_is_cached() {
+    let package_path = get_sample_path().join("./services/ledger");
+    let plugin =
+        ArchetypePlugin::from_program(get_sample_path().join("./tools/mrt-archetype-acme"))
+            .unwrap();
+
+    assert!(plugin.matcher(&package_path));
+    assert!(!plugin.matcher(&get_sample_path()));
+
+    // Clones share the answers, a program that cannot start would fail otherwise
+    let moved = ArchetypePlugin {
+        program: PathBuf::from("./does-not-exist"),
+        ..plugin.clone()
+    };
+    assert!(moved.matcher(&package_path));
+    assert!(!moved.matcher(&get_sample_path()));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_plugin_timeout() {
+    let plugin =
+        ArchetypePlugin::from_program(get_sample_path().join("./tools/mrt-archetype-slow"))
+            .unwrap()
+            .with_timeout(Duration::from_millis(200));
+
+    let started = Instant::now();
+    let err = plugin
+        .request::<DetectResponse>("detect", &PluginRequest::new(&get_sample_path()))
+        .unwrap_err();
+
+    assert!(started.elapsed() < Duration::from_secs(5));
+    assert!(format!("{:#}", err).contains("did not answer `detect`"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_plugin_archetype() -> anyhow::Result<()> {
+    let project = crate::project::Project::read(Some(get_sample_path().join("mrt.yml")))?;
+    let package = project.read_package(PathBuf::from("./services/ledger"))?;
+
+    assert_eq!(package.archetype_id, "acme");
+    assert_eq!(package.name, "ledger");
+    assert_eq!(package.version, "5.0.0");
+    assert_eq!(package.metadata.scripts, vec!["build", "test"]);
+
+    let archetype = project
+        .get_archetype_registry()
+        .get_archetype_by_id("acme")
+        .unwrap();
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
+    let context = ScriptRunContext {
+        script_spec: "build",
+        script_args: &["--release".to_string()],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+
+    assert!(runner.can_run_script(&context)?);
+
+    let result = runner.run_script(&context)?;
+
+    assert!(result.result_type.is_success());
+    assert_eq!(result.stdout, "acme build ledger --release");
+
+    let context = ScriptRunContext {
+        script_spec: "deploy",
+        ..context
+    };
+    assert!(!runner.can_run_script(&context)?);
+
+    Ok(())
+}
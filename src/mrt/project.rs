@@ -3,25 +3,42 @@ use glob::glob;
 use log::warn;
 use std::path::PathBuf;
 
-use crate::manifest::Manifest;
+use crate::archetypes::ArchetypeRegistry;
+use crate::codeowners::CodeOwners;
+use crate::graph::PackageGraph;
+use crate::manifest::{Manifest, RunnerPreferences, matches_package_glob};
 use crate::package::{Package, PackageStatus};
+use crate::plugins::discover_plugins;
+use crate::runners::{CombinedScriptRunner, ScriptRunCache, ScriptRunner};
 
 #[derive(Debug)]
 pub struct Project {
     root_path: PathBuf,
     manifest: Manifest,
+    registry: ArchetypeRegistry,
+    codeowners: Option<CodeOwners>,
+    script_run_cache: ScriptRunCache,
 }
 
 impl Project {
     /// Read project from manifest path if defined. if not will use current directory and mrt.yml file
     pub fn read(manifest_path: Option<PathBuf>) -> Result<Project> {
-        let root_path = match manifest_path {
+        Project::read_with_registry(manifest_path, ArchetypeRegistry::builtin())
+    }
+
+    /// Same as `read`, detecting packages with archetypes from the given registry.
+    /// Archetype plugins are registered in front of them
+    pub fn read_with_registry(
+        manifest_path: Option<PathBuf>,
+        mut registry: ArchetypeRegistry,
+    ) -> Result<Project> {
+        let (root_path, manifest) = match manifest_path {
             Some(path) => {
                 // Here means user specified manifest path
                 // and we assume he knows what he is doing
                 let manifest_path = path.canonicalize()?;
 
-                manifest_path
+                let root_path = manifest_path
                     .parent()
                     .with_context(|| {
                         format!(
@@ -29,14 +46,38 @@ impl Project {
                             manifest_path
                         )
                     })?
-                    .canonicalize()?
+                    .canonicalize()?;
+
+                (root_path, Manifest::from_path(&manifest_path)?)
+            }
+            None => {
+                let root_path = std::env::current_dir()?;
+                let manifest_path = root_path.join("mrt.yml");
+
+                let manifest = match manifest_path.exists() {
+                    true => Manifest::from_path(&manifest_path)?,
+                    false => Manifest::new(),
+                };
+
+                (root_path, manifest)
             }
-            None => std::env::current_dir()?,
         };
 
+        discover_plugins(&root_path, &manifest.plugins).register(&mut registry);
+
+        manifest.validate(&registry)?;
+
+        let codeowners = CodeOwners::find(&root_path).unwrap_or_else(|err| {
+            warn!("Cannot read CODEOWNERS: {:#}", err);
+            None
+        });
+
         Ok(Project {
             root_path,
-            manifest: Manifest::new(),
+            manifest,
+            registry,
+            codeowners,
+            script_run_cache: ScriptRunCache::default(),
         })
     }
 
@@ -44,14 +85,90 @@ impl Project {
         &self.root_path
     }
 
+    pub fn get_manifest(&self) -> &Manifest {
+        &self.manifest
+    }
+
+    pub fn get_archetype_registry(&self) -> &ArchetypeRegistry {
+        &self.registry
+    }
+
+    pub fn get_runner_preferences(&self, package: &Package) -> RunnerPreferences {
+        self.manifest
+            .get_runner_preferences(&package.path, &package.archetype_id)
+    }
+
+    /// Scripts discovered by runners, reused by every runner built for the project packages
+    pub fn get_script_run_cache(&self) -> &ScriptRunCache {
+        &self.script_run_cache
+    }
+
+    /// Script runner of the package, combining runners of every detected archetype
+    pub fn get_script_runner(&self, package: &Package) -> Option<Box<dyn ScriptRunner>> {
+        let mut runners: Vec<Box<dyn ScriptRunner>> = vec![];
+
+        for archetype_id in &package.archetype_ids {
+            let archetype = self.registry.get_archetype_by_id(archetype_id)?;
+            let preferences = self
+                .manifest
+                .get_runner_preferences(&package.path, archetype_id);
+            runners.push(archetype.get_script_runner(&preferences));
+        }
+
+        match runners.len() {
+            0 => None,
+            1 => runners.pop(),
+            _ => Some(Box::new(CombinedScriptRunner::new(runners))),
+        }
+    }
+
     pub fn read_package(&self, package_path: PathBuf) -> Result<Package> {
-        Package::from_package_path(self.root_path.join(package_path), self.root_path.clone())
+        let mut package = Package::from_package_path(
+            self.root_path.join(package_path),
+            self.root_path.clone(),
+            &self.registry,
+            &self.manifest,
+        )?;
+
+        // Owners in the manifest take precedence over CODEOWNERS
+        if package.owners.is_empty()
+            && let Some(codeowners) = &self.codeowners
+        {
+            package.owners = codeowners.get_owners(&package.path);
+        }
+
+        Ok(package)
+    }
+
+    /// Package globs from the manifest merged with globs imported from workspace definitions
+    pub fn get_package_globs(&self) -> Vec<String> {
+        let mut package_globs = self.manifest.packages.clone();
+
+        for source in &self.manifest.workspaces {
+            match source.read_package_globs(&self.root_path) {
+                Ok(globs) => package_globs.extend(globs),
+                Err(err) => warn!("Cannot import {:?} workspace globs: {:#}", source, err),
+            }
+        }
+
+        let mut unique_globs: Vec<String> = vec![];
+        for package_glob in package_globs {
+            if !unique_globs.contains(&package_glob) {
+                unique_globs.push(package_glob);
+            }
+        }
+        unique_globs
     }
 
     pub fn get_packages(&self, all: bool) -> Vec<Package> {
         let mut packages: Vec<Package> = vec![];
 
-        for package_glob in &self.manifest.packages {
+        let (exclude_globs, package_globs): (Vec<String>, Vec<String>) = self
+            .get_package_globs()
+            .into_iter()
+            .partition(|package_glob| package_glob.starts_with('!'));
+
+        for package_glob in &package_globs {
             let rooted_package_glob = self.root_path.join(package_glob);
 
             let full_glob = rooted_package_glob.to_str().unwrap_or_else(|| {
@@ -66,8 +183,20 @@ impl Project {
                         .for_each(|path| {
                             let package = self.read_package(path).unwrap();
 
+                            let is_excluded = exclude_globs.iter().any(|exclude_glob| {
+                                matches_package_glob(&exclude_glob[1..], &package.path)
+                            });
+                            // Same package can be matched by several globs
+                            let is_duplicate = packages
+                                .iter()
+                                .any(|existing| existing.absolute_path == package.absolute_path);
+
+                            if is_excluded || is_duplicate {
+                                return;
+                            }
+
                             match package.status {
-                                PackageStatus::Valid => {
+                                PackageStatus::Valid | PackageStatus::DependencyCycle(_) => {
                                     packages.push(package);
                                 }
                                 PackageStatus::CannotRead(ref message) => {
@@ -100,6 +229,164 @@ impl Project {
             }
         }
 
+        // Cycles would deadlock ordered runs, so they are reported on every member
+        let graph = PackageGraph::new(packages.clone());
+        for cycle in graph.get_cycles() {
+            let description = graph.describe_cycle(&cycle);
+            for index in cycle {
+                if let PackageStatus::Valid = packages[index].status {
+                    packages[index].status = PackageStatus::DependencyCycle(description.clone());
+                }
+            }
+        }
+
         packages
     }
+
+    /// Dependencies between packages of the project
+    pub fn get_package_graph(&self) -> PackageGraph {
+        PackageGraph::new(self.get_packages(false))
+    }
+}
+
+#[test]
+fn test_get_packages_from_workspaces() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/workspaces-sample/mrt.yml");
+    let project = Project::read(Some(project_path))?;
+
+    let mut package_paths: Vec<String> = project
+        .get_packages(true)
+        .into_iter()
+        .map(|package| package.path)
+        .collect();
+    package_paths.sort();
+
+    assert_eq!(
+        package_paths,
+        vec!["crates/rust-lib", "libs/node-lib", "tools/node-tool"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_package_with_several_archetypes() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/polyglot-sample/mrt.yml");
+    let project = Project::read(Some(project_path))?;
+    let package = project.read_package(PathBuf::from("./packages/web-tools"))?;
+
+    assert_eq!(package.archetype_id, "nodejs/npm");
+    assert_eq!(package.archetype_ids, vec!["nodejs/npm", "python/pep621"]);
+
+    let runner = project.get_script_runner(&package).unwrap();
+    let context = crate::runners::ScriptRunContext {
+        script_spec: "lint",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+    let selected: Vec<String> = runner
+        .explain_script(&context)
+        .into_iter()
+        .filter(|candidate| candidate.selected)
+        .map(|candidate| candidate.runner_id)
+        .collect();
+
+    assert_eq!(selected, vec!["npm", "pdm"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_package_archetype_override() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/polyglot-sample/mrt.yml");
+    let project = Project::read(Some(project_path))?;
+    let package = project.read_package(PathBuf::from("./packages/py-tooling"))?;
+
+    assert_eq!(package.name, "py-tooling");
+    assert_eq!(package.archetype_ids, vec!["python/pep621"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_package_tags_and_owners() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/ownership-sample/mrt.yml");
+    let project = Project::read(Some(project_path))?;
+
+    let billing = project.read_package(PathBuf::from("./packages/billing"))?;
+    assert_eq!(billing.tags, vec!["library", "service"]);
+    assert_eq!(billing.owners, vec!["@acme/payments"]);
+
+    // No owners in the manifest, so they come from CODEOWNERS
+    let admin = project.read_package(PathBuf::from("./apps/admin"))?;
+    assert_eq!(admin.tags, vec!["service"]);
+    assert_eq!(admin.owners, vec!["@acme/frontend"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_get_packages_with_dependency_cycle() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/graph-sample/mrt.yml");
+    let project = Project::read(Some(project_path))?;
+
+    let packages = project.get_packages(false);
+    let status_of = |name: &str| {
+        packages
+            .iter()
+            .find(|package| package.name == name)
+            .map(|package| package.status.to_string())
+            .unwrap()
+    };
+
+    assert!(status_of("ping").starts_with("Warning: dependency cycle"));
+    assert!(
+        status_of("pong").contains("ping -> pong") || status_of("pong").contains("pong -> ping")
+    );
+    assert_eq!(status_of("web"), "Valid");
+
+    Ok(())
+}
+
+#[test]
+fn test_script_run_cache_reused_by_runners() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = Project::read(Some(project_path))?;
+    let package = project.read_package(PathBuf::from("./packages/py-lib2"))?;
+    let context = crate::runners::ScriptRunContext {
+        script_spec: "format",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: project.get_script_run_cache(),
+    };
+
+    // New runner for every call, same as `mrt run --explain` and `mrt run`
+    project
+        .get_script_runner(&package)
+        .unwrap()
+        .explain_script(&context);
+    assert!(
+        project
+            .get_script_runner(&package)
+            .unwrap()
+            .can_run_script(&context)?
+    );
+    let result = project
+        .get_script_runner(&package)
+        .unwrap()
+        .run_script(&context)?;
+    assert!(result.result_type.is_success());
+
+    assert_eq!(project.get_script_run_cache().get_discovery_count(), 1);
+
+    Ok(())
 }
@@ -0,0 +1,181 @@
+use anyhow::{Context, Result, bail};
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+use toml::{Table, Value};
+
+use crate::package::{DYNAMIC_VERSION, PackageMetadata, deserialize_lenient};
+
+/// Trove classifier that makes PyPI reject uploads
+const PRIVATE_CLASSIFIER: &str = "Private :: Do Not Upload";
+
+// Standard `[project]` table of pyproject.toml, see PEP 621
+#[derive(Deserialize, Debug)]
+pub(crate) struct PyProjectProject {
+    pub name: String,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub dynamic: Vec<String>,
+    // Informational fields are optional, a value of wrong type is ignored
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub description: Option<String>,
+    // SPDX expression, or `{ text = ... }` / `{ file = ... }` before PEP 639
+    pub license: Option<Value>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub authors: Option<Vec<PyProjectPerson>>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub keywords: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub classifiers: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    pub urls: Option<BTreeMap<String, String>>,
+    /// PEP 508 requirements, like `requests>=2.0`
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+    #[serde(default, rename = "optional-dependencies")]
+    pub optional_dependencies: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct PyProjectPerson {
+    pub name: Option<String>,
+    pub email: Option<String>,
+}
+
+impl PyProjectProject {
+    pub fn resolve_version(&self) -> Result<&str> {
+        match &self.version {
+            Some(version) => Ok(version.as_str()),
+            None if self.dynamic.iter().any(|field| field == "version") => Ok(DYNAMIC_VERSION),
+            None => bail!("`project.version` is missing and is not declared as dynamic"),
+        }
+    }
+
+    /// Metadata of the `[project]` table, scripts are resolved by the archetype
+    pub fn get_metadata(&self) -> PackageMetadata {
+        let license = match &self.license {
+            Some(Value::String(license)) => Some(license.clone()),
+            Some(license) => license
+                .get("text")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            None => None,
+        };
+
+        // Project URL labels are free-form, see the PyPA well-known labels
+        let repository = self
+            .urls
+            .iter()
+            .flatten()
+            .find(|(label, _)| {
+                matches!(
+                    label
+                        .to_lowercase()
+                        .replace(['-', '_', ' ', '.'], "")
+                        .as_str(),
+                    "repository" | "source" | "sourcecode" | "github" | "code"
+                )
+            })
+            .map(|(_, url)| url.clone());
+
+        PackageMetadata {
+            description: self.description.clone(),
+            private: self
+                .classifiers
+                .iter()
+                .flatten()
+                .any(|classifier| classifier == PRIVATE_CLASSIFIER)
+                .then_some(true),
+            license,
+            authors: self
+                .authors
+                .iter()
+                .flatten()
+                .filter_map(|author| author.name.clone().or_else(|| author.email.clone()))
+                .collect(),
+            repository,
+            keywords: self.keywords.clone().unwrap_or_default(),
+            scripts: vec![],
+            dependencies: self
+                .dependencies
+                .iter()
+                .chain(self.optional_dependencies.values().flatten())
+                .filter_map(|requirement| requirement_name(requirement))
+                .collect(),
+        }
+    }
+}
+
+/// Project name of a PEP 508 requirement, e.g. `requests` of `requests[socks]>=2.0`
+pub(crate) fn requirement_name(requirement: &str) -> Option<String> {
+    let name: String = requirement
+        .trim()
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        .collect();
+
+    Some(name).filter(|name| !name.is_empty())
+}
+
+/// Normalized project name, see PEP 503: `Acme_Utils` and `acme.utils` are the same project
+pub(crate) fn normalize_name(name: &str) -> String {
+    let mut normalized = String::with_capacity(name.len());
+    for c in name.chars() {
+        if matches!(c, '-' | '_' | '.') {
+            if !normalized.ends_with('-') {
+                normalized.push('-');
+            }
+        } else {
+            normalized.push(c.to_ascii_lowercase());
+        }
+    }
+    normalized
+}
+
+/// Requirement names of PEP 735 `[dependency-groups]`, group includes are skipped
+pub(crate) fn get_dependency_groups(pyproject: &Table) -> Vec<String> {
+    get_table(pyproject, &["dependency-groups"])
+        .into_iter()
+        .flat_map(|groups| groups.values())
+        .filter_map(Value::as_array)
+        .flatten()
+        .filter_map(Value::as_str)
+        .filter_map(requirement_name)
+        .collect()
+}
+
+pub(crate) fn read_toml(path: &Path) -> Result<Table> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+
+    toml::from_str(&content).with_context(|| format!("Failed to parse {}", path.display()))
+}
+
+pub(crate) fn get_table<'a>(table: &'a Table, keys: &[&str]) -> Option<&'a Table> {
+    keys.iter()
+        .try_fold(table, |table, key| table.get(*key)?.as_table())
+}
+
+pub(crate) fn get_keys(table: &Table, keys: &[&str]) -> Vec<String> {
+    get_table(table, keys)
+        .map(|table| table.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn test_requirement_name() {
+    assert_eq!(
+        requirement_name("requests[socks] >= 2.0; python_version < '3.11'"),
+        Some("requests".to_string())
+    );
+    assert_eq!(
+        requirement_name("acme.utils>=1.0"),
+        Some("acme.utils".to_string())
+    );
+    assert_eq!(requirement_name(""), None);
+}
+
+#[test]
+fn test_normalize_name() {
+    assert_eq!(normalize_name("Acme_Utils"), "acme-utils");
+    assert_eq!(normalize_name("acme.-utils"), "acme-utils");
+}
@@ -0,0 +1,162 @@
+use anyhow::{Context, Result};
+use log::warn;
+use serde::Deserialize;
+use std::path::Path;
+
+use super::runner::PythonProjectTool;
+use crate::package::{PackageInfoExtractor, PackageMetadata};
+use crate::pyproject::{PyProjectProject, get_dependency_groups, get_keys, read_toml};
+
+pub struct Pep621PackageInfoExtractor {
+    name: String,
+    version: String,
+    metadata: PackageMetadata,
+}
+
+#[derive(Deserialize, Debug)]
+struct Pep621PyProject {
+    project: PyProjectProject,
+}
+
+impl Pep621PackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<Pep621PackageInfoExtractor> {
+        let pyproject_toml_path = package_path.join("pyproject.toml");
+
+        let pyproject_toml_content =
+            std::fs::read_to_string(&pyproject_toml_path).with_context(|| {
+                format!(
+                    "Failed to read pyproject.toml file at {}",
+                    pyproject_toml_path.display()
+                )
+            })?;
+
+        let pyproject: Pep621PyProject = toml::from_str(&pyproject_toml_content)?;
+
+        let mut metadata = pyproject.project.get_metadata();
+        // Scripts are informational, the package is still readable without them
+        metadata.scripts = list_scripts(package_path).unwrap_or_else(|err| {
+            warn!(
+                "Cannot list scripts of {}: {:#}",
+                package_path.display(),
+                err
+            );
+            vec![]
+        });
+        metadata
+            .dependencies
+            .extend(get_dependency_groups(&read_toml(&pyproject_toml_path)?));
+
+        Ok(Pep621PackageInfoExtractor {
+            version: pyproject.project.resolve_version()?.to_string(),
+            name: pyproject.project.name,
+            metadata,
+        })
+    }
+}
+
+/// PDM and Hatch scripts, then `[project.scripts]` entry points
+fn list_scripts(package_path: &Path) -> Result<Vec<String>> {
+    let mut scripts = PythonProjectTool::Pdm.list_scripts(package_path)?;
+    scripts.extend(PythonProjectTool::Hatch.list_scripts(package_path)?);
+    scripts.extend(get_keys(
+        &read_toml(&package_path.join("pyproject.toml"))?,
+        &["project", "scripts"],
+    ));
+
+    let mut unique = vec![];
+    for script in scripts {
+        if !unique.contains(&script) {
+            unique.push(script);
+        }
+    }
+    Ok(unique)
+}
+
+impl PackageInfoExtractor for Pep621PackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+
+    fn get_metadata(&self) -> PackageMetadata {
+        self.metadata.clone()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = Pep621PackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_success() {
+    let lib_path =
+        crate::testing::utils::get_repo_root().join("./references/python-sample/packages/uv-lib");
+    let result = Pep621PackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "uv-lib");
+    assert_eq!(result.get_version(), "0.3.0");
+}
+
+#[test]
+fn test_from_package_path_dynamic_version() {
+    let lib_path = crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages/hatch-lib");
+    let result = Pep621PackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_version(), crate::package::DYNAMIC_VERSION);
+}
+
+#[test]
+fn test_metadata() {
+    let lib_path =
+        crate::testing::utils::get_repo_root().join("./references/python-sample/packages/pdm-lib");
+    let metadata = Pep621PackageInfoExtractor::from_package_path(&lib_path)
+        .unwrap()
+        .get_metadata();
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some("Sample package managed by PDM")
+    );
+    assert_eq!(metadata.private, Some(true));
+    assert_eq!(metadata.license.as_deref(), Some("Apache-2.0"));
+    assert_eq!(metadata.authors, vec!["Jane Doe"]);
+    assert_eq!(
+        metadata.repository.as_deref(),
+        Some("https://github.com/example/pdm-lib")
+    );
+    assert_eq!(metadata.keywords, vec!["sample"]);
+    assert!(metadata.scripts.contains(&"check".to_string()));
+}
+
+#[test]
+fn test_from_package_path_invalid_hatch_toml() {
+    let lib_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("./fixures/invalid-hatch-toml-lib");
+
+    // Scripts cannot be listed, but name, version and metadata are still read
+    let result = Pep621PackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "invalid-hatch-toml-lib");
+    assert_eq!(result.get_version(), "0.1.0");
+    assert!(result.get_metadata().scripts.is_empty());
+}
+
+#[test]
+fn test_malformed_metadata() {
+    let lib_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("./fixures/malformed-metadata-lib");
+    let result = Pep621PackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    let metadata = result.get_metadata();
+    assert_eq!(result.get_name(), "malformed-metadata-lib");
+    assert!(metadata.authors.is_empty());
+    assert!(metadata.keywords.is_empty());
+    assert_eq!(metadata.private, None);
+    assert_eq!(metadata.dependencies, vec!["requests"]);
+}
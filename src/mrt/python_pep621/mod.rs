@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    python_poetry::PythonPoetryArchetype,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{
+    info::Pep621PackageInfoExtractor,
+    runner::{PythonProjectScriptRunner, PythonProjectTool},
+};
+
+mod info;
+mod runner;
+
+/// Python packages described by the standard `[project]` table,
+/// managed by uv, Hatch, PDM or plain setuptools
+pub struct PythonPep621Archetype {}
+
+impl Archetype for PythonPep621Archetype {
+    fn get_id(&self) -> &str {
+        "python/pep621"
+    }
+
+    fn matcher(&self, path: &Path) -> bool {
+        // Poetry packages may also have `[project]` table, they are handled by Poetry archetype
+        if (PythonPoetryArchetype {}).matcher(path) {
+            return false;
+        }
+
+        let pyproject = path.join("pyproject.toml");
+
+        match std::fs::read_to_string(pyproject) {
+            Ok(contents) => contents
+                .parse::<toml::Table>()
+                .is_ok_and(|pyproject| pyproject.contains_key("project")),
+            Err(_) => false,
+        }
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        // Each tool runner only accepts packages where that tool is configured
+        let runners: Vec<Box<dyn ScriptRunner>> = [
+            PythonProjectTool::Pdm,
+            PythonProjectTool::Hatch,
+            PythonProjectTool::Uv,
+        ]
+        .into_iter()
+        .map(|tool| Box::new(PythonProjectScriptRunner::new(tool)) as Box<dyn ScriptRunner>)
+        .collect();
+
+        Box::from(
+            WrapperScriptRunner::wrap_all_with_generic_runners(runners)
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            Pep621PackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
+
+#[test]
+fn test_script_runner_explain() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/python-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/pdm-lib"))?;
+
+    assert_eq!(package.archetype_id, "python/pep621");
+
+    let archetype = PythonPep621Archetype {};
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
+
+    let context = crate::runners::ScriptRunContext {
+        script_spec: "check",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+    let explanation = crate::runners::ScriptRunExplanation::new(runner.explain_script(&context));
+
+    assert_eq!(explanation.selected_runner_id.as_deref(), Some("pdm"));
+
+    Ok(())
+}
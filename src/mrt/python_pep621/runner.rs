@@ -0,0 +1,142 @@
+use anyhow::Result;
+use std::path::Path;
+use toml::Value;
+
+use crate::package::find_in_ancestors;
+use crate::pyproject::{get_keys, get_table, read_toml};
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PythonProjectTool {
+    Uv,
+    Hatch,
+    Pdm,
+}
+
+impl PythonProjectTool {
+    pub fn get_id(&self) -> &'static str {
+        match self {
+            PythonProjectTool::Uv => "uv",
+            PythonProjectTool::Hatch => "hatch",
+            PythonProjectTool::Pdm => "pdm",
+        }
+    }
+
+    /// Scripts the tool can run for the package, empty when the tool is not configured
+    pub fn list_scripts(&self, package_path: &Path) -> Result<Vec<String>> {
+        let pyproject = read_toml(&package_path.join("pyproject.toml"))?;
+
+        Ok(match self {
+            PythonProjectTool::Uv => {
+                // uv has no task runner, but runs `[project.scripts]` entry points.
+                // uv.lock of a workspace is in its root, so look up to the repository root
+                let uv_lock_exists = find_in_ancestors(package_path, "uv.lock").is_some();
+
+                if uv_lock_exists || get_table(&pyproject, &["tool", "uv"]).is_some() {
+                    get_keys(&pyproject, &["project", "scripts"])
+                } else {
+                    vec![]
+                }
+            }
+            PythonProjectTool::Hatch => {
+                // Environments live in `[tool.hatch.envs]` or in `[envs]` of hatch.toml
+                let hatch_toml_path = package_path.join("hatch.toml");
+                let mut envs = get_table(&pyproject, &["tool", "hatch", "envs"]).cloned();
+                if hatch_toml_path.is_file() {
+                    envs = get_table(&read_toml(&hatch_toml_path)?, &["envs"]).cloned();
+                }
+
+                envs.unwrap_or_default()
+                    .iter()
+                    .flat_map(|(env, settings)| {
+                        let scripts = settings
+                            .get("scripts")
+                            .and_then(Value::as_table)
+                            .map(|scripts| scripts.keys().cloned().collect())
+                            .unwrap_or_else(Vec::new);
+
+                        // Scripts of non-default environments are addressed as `env:script`
+                        scripts.into_iter().map(move |script| match env.as_str() {
+                            "default" => script,
+                            env => format!("{}:{}", env, script),
+                        })
+                    })
+                    .collect()
+            }
+            PythonProjectTool::Pdm => get_keys(&pyproject, &["tool", "pdm", "scripts"])
+                .into_iter()
+                // `_` holds settings shared by all scripts
+                .filter(|script| script != "_")
+                .collect(),
+        })
+    }
+}
+
+pub struct PythonProjectScriptRunner {
+    tool: PythonProjectTool,
+    runner: CommandRunner,
+}
+
+impl PythonProjectScriptRunner {
+    pub fn new(tool: PythonProjectTool) -> PythonProjectScriptRunner {
+        PythonProjectScriptRunner {
+            tool,
+            runner: CommandRunner::new(tool.get_id().to_string()),
+        }
+    }
+}
+
+impl ScriptRunner for PythonProjectScriptRunner {
+    fn get_id(&self) -> &str {
+        self.tool.get_id()
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let mut args = vec!["run".to_string(), context.script_spec.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        self.runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(self
+            .tool
+            .list_scripts(&context.package.absolute_path)?
+            .iter()
+            .any(|script| script == context.script_spec))
+    }
+}
+
+#[cfg(test)]
+fn get_sample_package_path(package: &str) -> std::path::PathBuf {
+    crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages")
+        .join(package)
+}
+
+#[test]
+fn test_list_scripts_uv() -> anyhow::Result<()> {
+    let scripts = PythonProjectTool::Uv.list_scripts(&get_sample_package_path("uv-lib"))?;
+    assert_eq!(scripts, vec!["uv-lib-cli"]);
+
+    let scripts = PythonProjectTool::Uv.list_scripts(&get_sample_package_path("pdm-lib"))?;
+    assert!(scripts.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn test_list_scripts_hatch() -> anyhow::Result<()> {
+    let scripts = PythonProjectTool::Hatch.list_scripts(&get_sample_package_path("hatch-lib"))?;
+    assert_eq!(scripts, vec!["check", "docs:serve"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_list_scripts_pdm() -> anyhow::Result<()> {
+    let scripts = PythonProjectTool::Pdm.list_scripts(&get_sample_package_path("pdm-lib"))?;
+    assert_eq!(scripts, vec!["check", "start"]);
+
+    Ok(())
+}
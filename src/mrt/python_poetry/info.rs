@@ -1,30 +1,132 @@
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use serde::Deserialize;
 use std::path::Path;
 
-use crate::package::PackageInfoExtractor;
+use crate::package::{DYNAMIC_VERSION, PackageInfoExtractor, PackageMetadata, deserialize_lenient};
+use crate::pyproject::{PyProjectProject, get_dependency_groups, get_keys, read_toml};
 
 pub struct PoetryPackageInfoExtractor {
-    poetry_pyproject: PoetryPyProject,
+    name: String,
+    version: String,
+    metadata: PackageMetadata,
 }
 
-#[derive(Deserialize, Debug)]
+// Poetry 2 keeps name and version in `[project]`, `[tool.poetry]` only has settings
+#[derive(Deserialize, Debug, Default)]
 struct PyProjectToolPoetry {
-    name: String,
-    version: String,
+    name: Option<String>,
+    version: Option<String>,
+    // Informational fields are optional, a value of wrong type is ignored
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    description: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    license: Option<String>,
+    // "Name <email>" strings
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    authors: Option<Vec<String>>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    repository: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_lenient")]
+    keywords: Option<Vec<String>>,
+    // Poetry only manages dependencies when `package-mode = false`
+    #[serde(rename = "package-mode")]
+    package_mode: Option<bool>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Default)]
+struct PoetryDynamicVersioning {
+    #[serde(default)]
+    enable: bool,
+}
+
+#[derive(Deserialize, Debug, Default)]
 struct PyProjectTool {
+    #[serde(default)]
     poetry: PyProjectToolPoetry,
+    #[serde(rename = "poetry-dynamic-versioning", default)]
+    poetry_dynamic_versioning: PoetryDynamicVersioning,
 }
 
 // Represents Poetry package info from pyproject.toml
 #[derive(Deserialize, Debug)]
 struct PoetryPyProject {
+    project: Option<PyProjectProject>,
+    // Poetry 2 packages may only declare Poetry as a build backend
+    #[serde(default)]
     tool: PyProjectTool,
 }
 
+impl PoetryPyProject {
+    fn resolve_name(&self) -> Result<&str> {
+        match (&self.project, &self.tool.poetry.name) {
+            (Some(project), _) => Ok(project.name.as_str()),
+            (None, Some(name)) => Ok(name.as_str()),
+            (None, None) => bail!("Neither `project.name` nor `tool.poetry.name` is defined"),
+        }
+    }
+
+    fn resolve_version(&self) -> Result<&str> {
+        // Version in pyproject.toml is only a placeholder when computed from VCS
+        if self.tool.poetry_dynamic_versioning.enable {
+            return Ok(DYNAMIC_VERSION);
+        }
+
+        if let Some(project) = &self.project {
+            // Poetry takes `tool.poetry.version` when `project.version` is dynamic
+            return match (&project.version, &self.tool.poetry.version) {
+                (None, Some(version)) => Ok(version.as_str()),
+                _ => project.resolve_version(),
+            };
+        }
+
+        match &self.tool.poetry.version {
+            Some(version) => Ok(version.as_str()),
+            None => bail!("Neither `project.version` nor `tool.poetry.version` is defined"),
+        }
+    }
+
+    /// `[project]` metadata, falling back to `[tool.poetry]` for missing fields
+    fn get_metadata(&self) -> PackageMetadata {
+        let poetry = &self.tool.poetry;
+        let mut metadata = self
+            .project
+            .as_ref()
+            .map(PyProjectProject::get_metadata)
+            .unwrap_or_default();
+
+        metadata.description = metadata.description.or(poetry.description.clone());
+        metadata.license = metadata.license.or(poetry.license.clone());
+        metadata.repository = metadata.repository.or(poetry.repository.clone());
+        if metadata.authors.is_empty() {
+            metadata.authors = poetry.authors.clone().unwrap_or_default();
+        }
+        if metadata.keywords.is_empty() {
+            metadata.keywords = poetry.keywords.clone().unwrap_or_default();
+        }
+        if poetry.package_mode == Some(false) {
+            metadata.private = Some(true);
+        }
+
+        metadata
+    }
+}
+
+/// Dependencies of `[tool.poetry]`, its dependency groups, and legacy dev-dependencies
+fn get_poetry_dependencies(pyproject: &toml::Table) -> Vec<String> {
+    let mut dependencies = get_keys(pyproject, &["tool", "poetry", "dependencies"]);
+    dependencies.extend(get_keys(pyproject, &["tool", "poetry", "dev-dependencies"]));
+    for group in get_keys(pyproject, &["tool", "poetry", "group"]) {
+        dependencies.extend(get_keys(
+            pyproject,
+            &["tool", "poetry", "group", &group, "dependencies"],
+        ));
+    }
+
+    // Python version constraint is declared as a dependency
+    dependencies.retain(|dependency| dependency != "python");
+    dependencies
+}
+
 impl PoetryPackageInfoExtractor {
     pub fn from_package_path(package_path: &Path) -> Result<PoetryPackageInfoExtractor> {
         let pyproject_toml_path = package_path.join("pyproject.toml");
@@ -37,19 +139,41 @@ impl PoetryPackageInfoExtractor {
                 )
             })?;
 
+        let poetry_pyproject: PoetryPyProject = toml::from_str(&pyproject_toml_content)?;
+
+        // Same order as the runner: poethepoet tasks, then Poetry scripts
+        let pyproject = read_toml(&pyproject_toml_path)?;
+        let mut metadata = poetry_pyproject.get_metadata();
+        metadata.scripts = get_keys(&pyproject, &["tool", "poe", "tasks"]);
+        metadata
+            .scripts
+            .extend(get_keys(&pyproject, &["tool", "poetry", "scripts"]));
+        metadata
+            .dependencies
+            .extend(get_poetry_dependencies(&pyproject));
+        metadata
+            .dependencies
+            .extend(get_dependency_groups(&pyproject));
+
         Ok(PoetryPackageInfoExtractor {
-            poetry_pyproject: toml::from_str(&pyproject_toml_content)?,
+            name: poetry_pyproject.resolve_name()?.to_string(),
+            version: poetry_pyproject.resolve_version()?.to_string(),
+            metadata,
         })
     }
 }
 
 impl PackageInfoExtractor for PoetryPackageInfoExtractor {
     fn get_name(&self) -> &str {
-        self.poetry_pyproject.tool.poetry.name.as_str()
+        self.name.as_str()
     }
 
     fn get_version(&self) -> &str {
-        self.poetry_pyproject.tool.poetry.version.as_str()
+        self.version.as_str()
+    }
+
+    fn get_metadata(&self) -> PackageMetadata {
+        self.metadata.clone()
     }
 }
 
@@ -67,3 +191,46 @@ fn test_from_package_path_success() {
     let result = PoetryPackageInfoExtractor::from_package_path(&lib1_path).unwrap();
     assert_eq!(result.get_name(), "py_lib2");
 }
+
+#[test]
+fn test_from_package_path_poetry2_project() {
+    let lib_path = crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages/poetry2-lib");
+    let result = PoetryPackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "poetry2-lib");
+    assert_eq!(result.get_version(), "2.0.1");
+}
+
+#[test]
+fn test_from_package_path_poetry2_without_tool() {
+    let lib_path = crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages/poetry2-core-lib");
+    let result = PoetryPackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "poetry2-core-lib");
+    assert_eq!(result.get_version(), "2.1.0");
+    assert_eq!(result.get_metadata().dependencies, vec!["httpx"]);
+}
+
+#[test]
+fn test_from_package_path_dynamic_version() {
+    let lib_path = crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages/poetry-dynamic-lib");
+    let result = PoetryPackageInfoExtractor::from_package_path(&lib_path).unwrap();
+    assert_eq!(result.get_name(), "poetry-dynamic-lib");
+    assert_eq!(result.get_version(), DYNAMIC_VERSION);
+}
+
+#[test]
+fn test_metadata() {
+    let app_path = crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages/poetry-app");
+    let metadata = PoetryPackageInfoExtractor::from_package_path(&app_path)
+        .unwrap()
+        .get_metadata();
+    assert_eq!(
+        metadata.description.as_deref(),
+        Some("Sample Poetry application with poethepoet tasks")
+    );
+    assert_eq!(metadata.authors, vec!["Mykhailo Chalyi <mike@chaliy.name>"]);
+    assert_eq!(metadata.scripts, vec!["check", "serve", "poetry-app-cli"]);
+}
@@ -1,12 +1,19 @@
-use std::{fs, path::Path};
+use std::path::Path;
 
 use anyhow::{Context, Result};
 
-use crate::{archetypes::Archetype, package::PackageInfoExtractor, runners::WrapperScriptRunner};
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    pyproject::{get_table, read_toml},
+    runners::WrapperScriptRunner,
+};
 
-use self::info::PoetryPackageInfoExtractor;
+use self::{info::PoetryPackageInfoExtractor, runner::PoetryScriptRunner};
 
 mod info;
+mod runner;
 
 pub struct PythonPoetryArchetype {}
 
@@ -19,15 +26,31 @@ impl Archetype for PythonPoetryArchetype {
         let pyproject = path.join("pyproject.toml");
 
         if pyproject.exists() {
-            let contents = fs::read_to_string(pyproject).unwrap_or_default();
-
-            return contents.contains("[tool.poetry]");
+            let Ok(pyproject) = read_toml(&pyproject) else {
+                return false;
+            };
+
+            // Poetry 2 projects may only declare Poetry as a build backend
+            let build_backend = pyproject
+                .get("build-system")
+                .and_then(|build_system| build_system.get("build-backend"))
+                .and_then(toml::Value::as_str)
+                .unwrap_or_default();
+
+            return get_table(&pyproject, &["tool", "poetry"]).is_some()
+                || build_backend.starts_with("poetry");
         }
         false
     }
 
-    fn get_script_runner(&self) -> Box<dyn crate::runners::ScriptRunner> {
-        Box::from(WrapperScriptRunner::generic_runners())
+    fn get_script_runner(
+        &self,
+        preferences: &RunnerPreferences,
+    ) -> Box<dyn crate::runners::ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(PoetryScriptRunner::new()))
+                .with_preferences(preferences),
+        )
     }
 
     fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
@@ -50,12 +73,14 @@ fn test_script_runner_make() -> anyhow::Result<()> {
 
     let archetype = PythonPoetryArchetype {};
 
-    let runner = archetype.get_script_runner();
+    let runner = archetype.get_script_runner(&project.get_runner_preferences(&package));
 
     let context = crate::runners::ScriptRunContext {
         script_spec: "format",
+        script_args: &[],
         package: &package,
         reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
     };
 
     assert!(runner.can_run_script(&context)?);
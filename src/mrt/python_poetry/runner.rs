@@ -0,0 +1,96 @@
+use anyhow::Result;
+use std::path::Path;
+
+use crate::pyproject::{get_keys, read_toml};
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+/// Runs poethepoet tasks and Poetry scripts via `poetry run`
+pub struct PoetryScriptRunner {
+    poetry_runner: CommandRunner,
+}
+
+impl PoetryScriptRunner {
+    pub fn new() -> PoetryScriptRunner {
+        PoetryScriptRunner {
+            poetry_runner: CommandRunner::new("poetry".to_string()),
+        }
+    }
+
+    fn get_run_args(
+        &self,
+        package_path: &Path,
+        script_spec: &str,
+        script_args: &[String],
+    ) -> Result<Option<Vec<String>>> {
+        let pyproject = read_toml(&package_path.join("pyproject.toml"))?;
+
+        // Tasks are meant for development workflows, so they win over entry points
+        let mut args = if get_keys(&pyproject, &["tool", "poe", "tasks"])
+            .iter()
+            .any(|task| task == script_spec)
+        {
+            vec!["run", "poe", script_spec]
+        } else if get_keys(&pyproject, &["tool", "poetry", "scripts"])
+            .iter()
+            .any(|script| script == script_spec)
+        {
+            vec!["run", script_spec]
+        } else {
+            return Ok(None);
+        }
+        .into_iter()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+
+        args.extend(script_args.iter().cloned());
+
+        Ok(Some(args))
+    }
+}
+
+impl ScriptRunner for PoetryScriptRunner {
+    fn get_id(&self) -> &str {
+        "poetry"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        match self.get_run_args(
+            &context.package.absolute_path,
+            context.script_spec,
+            context.script_args,
+        )? {
+            Some(args) => self.poetry_runner.run_script(args, context),
+            None => Ok(ScriptRunResult::noop()),
+        }
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(self
+            .get_run_args(&context.package.absolute_path, context.script_spec, &[])?
+            .is_some())
+    }
+}
+
+#[test]
+fn test_get_run_args() -> anyhow::Result<()> {
+    let app_path = crate::testing::utils::get_repo_root()
+        .join("./references/python-sample/packages/poetry-app");
+    let runner = PoetryScriptRunner::new();
+
+    assert_eq!(
+        runner.get_run_args(&app_path, "check", &["--fix".to_string()])?,
+        Some(vec![
+            "run".to_string(),
+            "poe".to_string(),
+            "check".to_string(),
+            "--fix".to_string()
+        ])
+    );
+    assert_eq!(
+        runner.get_run_args(&app_path, "poetry-app-cli", &[])?,
+        Some(vec!["run".to_string(), "poetry-app-cli".to_string()])
+    );
+    assert_eq!(runner.get_run_args(&app_path, "no-such-script", &[])?, None);
+
+    Ok(())
+}
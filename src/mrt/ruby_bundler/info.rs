@@ -0,0 +1,133 @@
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+
+use crate::package::PackageInfoExtractor;
+
+pub struct BundlerPackageInfoExtractor {
+    name: String,
+    version: String,
+}
+
+pub(crate) fn find_gemspec(package_path: &Path) -> Option<PathBuf> {
+    let pattern = package_path.join("*.gemspec");
+    let mut gemspecs: Vec<PathBuf> = glob::glob(&pattern.to_string_lossy())
+        .ok()?
+        .filter_map(Result::ok)
+        .collect();
+
+    gemspecs.sort();
+    gemspecs.into_iter().next()
+}
+
+impl BundlerPackageInfoExtractor {
+    pub fn from_package_path(package_path: &Path) -> Result<BundlerPackageInfoExtractor> {
+        let directory_name = package_path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| String::from("n/a"));
+
+        // Applications like Rails only have Gemfile, they are not versioned
+        let Some(gemspec_path) = find_gemspec(package_path) else {
+            if !package_path.join("Gemfile").is_file() {
+                anyhow::bail!(
+                    "Failed to find Gemfile or .gemspec file at {}",
+                    package_path.display()
+                );
+            }
+
+            return Ok(BundlerPackageInfoExtractor {
+                name: directory_name,
+                version: String::from("n/a"),
+            });
+        };
+
+        let gemspec_content = std::fs::read_to_string(&gemspec_path).with_context(|| {
+            format!("Failed to read gemspec file at {}", gemspec_path.display())
+        })?;
+
+        let name = find_spec_attribute(&gemspec_content, "name")
+            .and_then(|value| parse_string_literal(&value))
+            .or_else(|| {
+                gemspec_path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().to_string())
+            })
+            .unwrap_or(directory_name);
+
+        // Version is usually a constant like `Money::VERSION` from `lib/**/version.rb`
+        let version = find_spec_attribute(&gemspec_content, "version")
+            .and_then(|value| {
+                parse_string_literal(&value).or_else(|| read_version_constant(package_path))
+            })
+            .unwrap_or_else(|| String::from("n/a"));
+
+        Ok(BundlerPackageInfoExtractor { name, version })
+    }
+}
+
+/// Right side of `spec.<attribute> = ...` in `Gem::Specification.new do |spec|` block
+fn find_spec_attribute(content: &str, attribute: &str) -> Option<String> {
+    content.lines().find_map(|line| {
+        let (left, right) = line.split_once('=')?;
+        let (_spec, name) = left.trim().split_once('.')?;
+
+        (name == attribute).then(|| right.trim().to_string())
+    })
+}
+
+fn parse_string_literal(value: &str) -> Option<String> {
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+
+    Some(value[..value.find(quote)?].to_string())
+}
+
+fn read_version_constant(package_path: &Path) -> Option<String> {
+    let pattern = package_path.join("lib/**/version.rb");
+
+    glob::glob(&pattern.to_string_lossy())
+        .ok()?
+        .filter_map(Result::ok)
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .find_map(|content| {
+            content.lines().find_map(|line| {
+                let (left, right) = line.split_once('=')?;
+                (left.trim() == "VERSION").then(|| parse_string_literal(right.trim()))?
+            })
+        })
+}
+
+impl PackageInfoExtractor for BundlerPackageInfoExtractor {
+    fn get_name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    fn get_version(&self) -> &str {
+        self.version.as_str()
+    }
+}
+
+#[test]
+fn test_from_package_path_no_folder_exists() {
+    let non_existing_package_path = std::path::PathBuf::from("does-not-exist");
+    let result = BundlerPackageInfoExtractor::from_package_path(&non_existing_package_path);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_from_package_path_gemspec() {
+    let gem_path =
+        crate::testing::utils::get_repo_root().join("./references/ruby-sample/gems/money");
+    let result = BundlerPackageInfoExtractor::from_package_path(&gem_path).unwrap();
+    assert_eq!(result.get_name(), "acme-money");
+    assert_eq!(result.get_version(), "1.4.2");
+}
+
+#[test]
+fn test_from_package_path_gemfile_only() {
+    let app_path =
+        crate::testing::utils::get_repo_root().join("./references/ruby-sample/apps/storefront");
+    let result = BundlerPackageInfoExtractor::from_package_path(&app_path).unwrap();
+    assert_eq!(result.get_name(), "storefront");
+    assert_eq!(result.get_version(), "n/a");
+}
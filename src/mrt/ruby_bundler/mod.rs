@@ -0,0 +1,47 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    archetypes::Archetype,
+    manifest::RunnerPreferences,
+    package::PackageInfoExtractor,
+    runners::{ScriptRunner, WrapperScriptRunner},
+};
+
+use self::{
+    info::{BundlerPackageInfoExtractor, find_gemspec},
+    runner::RakeScriptRunner,
+};
+
+mod info;
+mod runner;
+
+pub struct RubyBundlerArchetype {}
+
+impl Archetype for RubyBundlerArchetype {
+    fn get_id(&self) -> &str {
+        "ruby/bundler"
+    }
+
+    fn matcher(&self, package_path: &Path) -> bool {
+        package_path.join("Gemfile").exists() || find_gemspec(package_path).is_some()
+    }
+
+    fn get_script_runner(&self, preferences: &RunnerPreferences) -> Box<dyn ScriptRunner> {
+        Box::from(
+            WrapperScriptRunner::wrap_with_generic_runners(Box::from(RakeScriptRunner::new()))
+                .with_preferences(preferences),
+        )
+    }
+
+    fn get_info_extractor(&self, package_path: &Path) -> Result<Box<dyn PackageInfoExtractor>> {
+        let extractor =
+            BundlerPackageInfoExtractor::from_package_path(package_path).context(format!(
+                "Get information extractor for package {}",
+                package_path.display()
+            ))?;
+
+        Ok(Box::from(extractor))
+    }
+}
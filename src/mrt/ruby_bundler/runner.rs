@@ -0,0 +1,129 @@
+use anyhow::{Context, Result};
+use std::{path::Path, process::Command};
+
+use crate::package::find_in_ancestors;
+use crate::runners::{CommandRunner, ScriptRunContext, ScriptRunResult, ScriptRunner};
+
+const RAKEFILE_NAMES: [&str; 4] = ["rakefile", "Rakefile", "rakefile.rb", "Rakefile.rb"];
+
+/// Runs Rake tasks, through Bundler when the package has a Gemfile
+pub struct RakeScriptRunner {}
+
+impl RakeScriptRunner {
+    pub fn new() -> RakeScriptRunner {
+        RakeScriptRunner {}
+    }
+
+    fn get_command(package_path: &Path) -> (CommandRunner, Vec<String>) {
+        match find_in_ancestors(package_path, "Gemfile") {
+            Some(_) => (
+                CommandRunner::new("bundle".to_string()),
+                vec!["exec".to_string(), "rake".to_string()],
+            ),
+            None => (CommandRunner::new("rake".to_string()), vec![]),
+        }
+    }
+
+    /// Fails when tasks cannot be listed, e.g. gems are not installed
+    fn has_task(&self, context: &ScriptRunContext) -> Result<bool> {
+        let tasks = discover_rake_tasks(&context.package.absolute_path)?;
+
+        Ok(tasks.iter().any(|task| task == context.script_spec))
+    }
+}
+
+fn discover_rake_tasks(package_path: &Path) -> Result<Vec<String>> {
+    if !RAKEFILE_NAMES
+        .iter()
+        .any(|name| package_path.join(name).is_file())
+    {
+        return Ok(vec![]);
+    }
+
+    // -A lists tasks without description as well
+    let (runner, mut args) = RakeScriptRunner::get_command(package_path);
+    args.push("-AT".to_string());
+
+    let output = Command::new(&runner.program)
+        .args(args)
+        .current_dir(package_path)
+        .output()
+        .with_context(|| format!("Failed to list Rake tasks at {}", package_path.display()))?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "Failed to list Rake tasks at {}: {}",
+            package_path.display(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(parse_rake_tasks(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse `rake name[args]  # description` lines of `rake -AT`
+fn parse_rake_tasks(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.strip_prefix("rake "))
+        .filter_map(|line| line.split_whitespace().next())
+        .map(|task| task.split('[').next().unwrap_or(task).to_string())
+        .collect()
+}
+
+impl ScriptRunner for RakeScriptRunner {
+    fn get_id(&self) -> &str {
+        "rake"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let (runner, mut args) = Self::get_command(&context.package.absolute_path);
+        args.push(context.script_spec.to_string());
+        args.extend(context.script_args.iter().cloned());
+
+        runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        self.has_task(context)
+    }
+}
+
+#[test]
+fn test_parse_rake_tasks() {
+    let output = r#"rake db:migrate          # Migrate the database
+rake default
+rake release[remote]     # Create tag and push gem
+rake test                # Run tests
+"#;
+
+    assert_eq!(
+        parse_rake_tasks(output),
+        vec!["db:migrate", "default", "release", "test"]
+    );
+}
+
+#[test]
+fn test_rake_script_runner_no_rakefile() -> anyhow::Result<()> {
+    let package_path =
+        crate::testing::utils::get_repo_root().join("./references/ruby-sample/gems/money/lib");
+    let package = crate::package::Package::from_package_path(
+        package_path.clone(),
+        package_path.canonicalize()?,
+        &crate::archetypes::ArchetypeRegistry::new(),
+        &crate::manifest::Manifest::new(),
+    )?;
+    let runner = RakeScriptRunner::new();
+    let context = ScriptRunContext {
+        script_spec: "test",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &crate::runners::ScriptRunCache::default(),
+    };
+
+    // Rake is not asked without a Rakefile, so this holds even when it is not installed
+    assert!(!runner.can_run_script(&context)?);
+
+    Ok(())
+}
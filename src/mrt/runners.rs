@@ -1,22 +1,78 @@
 use anyhow::{Context, Result};
+use log::warn;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 use std::{
-    io::{BufRead, BufReader},
+    collections::HashMap,
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
     process::{Command, Stdio},
+    sync::{
+        Mutex, MutexGuard, PoisonError,
+        atomic::{AtomicUsize, Ordering},
+    },
 };
 
+use crate::just::discover_just_recipes;
+use crate::make::{MakeTarget, discover_make_targets};
+use crate::manifest::RunnerPreferences;
 use crate::progress::ProgressReporter;
+use crate::taskfile::discover_taskfile_tasks;
 
 use super::package::Package;
 
 pub struct ScriptRunContext<'a> {
     pub script_spec: &'a str,
+    /// Extra arguments forwarded to the script
+    pub script_args: &'a [String],
     pub package: &'a Package,
     pub reporter: &'a dyn ProgressReporter,
+    /// Scripts already discovered in the package, shared by every runner of the project
+    pub cache: &'a ScriptRunCache,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Scripts discovered by runners, per runner and package directory.
+///
+/// Runners are built for every script run, so discovered scripts are kept by the project
+/// and reused when the same package is probed, explained, and run.
+#[derive(Debug, Default)]
+pub struct ScriptRunCache {
+    scripts: Mutex<HashMap<(String, PathBuf), Vec<String>>>,
+    discoveries: AtomicUsize,
+}
+
+impl ScriptRunCache {
+    /// Cached scripts of the runner in the package, discovered on first use.
+    /// Failed discoveries are not cached
+    pub fn get_or_discover(
+        &self,
+        runner_id: &str,
+        package_path: &Path,
+        discover: impl FnOnce() -> Result<Vec<String>>,
+    ) -> Result<Vec<String>> {
+        let key = (runner_id.to_string(), package_path.to_path_buf());
+        if let Some(scripts) = self.lock().get(&key) {
+            return Ok(scripts.clone());
+        }
+
+        // Lock is not held while discovering, packages are probed in parallel
+        let scripts = discover()?;
+        self.discoveries.fetch_add(1, Ordering::Relaxed);
+        self.lock().insert(key, scripts.clone());
+
+        Ok(scripts)
+    }
+
+    /// Number of discoveries that could not be answered from the cache
+    pub fn get_discovery_count(&self) -> usize {
+        self.discoveries.load(Ordering::Relaxed)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<(String, PathBuf), Vec<String>>> {
+        self.scripts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ScriptRunResultType {
     Success,
     Error(String),
@@ -40,11 +96,81 @@ pub struct ScriptRunResult {
     pub exit_code: i32,
     pub stdout: String,
     pub stderr: String,
+    /// Why the script was not run, for example a dependency failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Answer of a single runner consulted for a script, see [`ScriptRunner::explain_script`]
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ScriptRunnerCandidate {
+    pub runner_id: String,
+    pub can_run: bool,
+    pub error: Option<String>,
+    /// Runner would execute the script
+    #[serde(default)]
+    pub selected: bool,
+}
+
+impl ScriptRunnerCandidate {
+    pub fn probe(runner: &(impl ScriptRunner + ?Sized), context: &ScriptRunContext) -> Self {
+        let (can_run, error) = match runner.can_run_script(context) {
+            Ok(can_run) => (can_run, None),
+            Err(err) => (false, Some(err.to_string())),
+        };
+
+        ScriptRunnerCandidate {
+            runner_id: runner.get_id().to_string(),
+            can_run,
+            error,
+            selected: false,
+        }
+    }
+}
+
+/// Runners consulted for a script in order, and the one that would execute it
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ScriptRunExplanation {
+    pub candidates: Vec<ScriptRunnerCandidate>,
+    pub selected_runner_id: Option<String>,
+}
+
+impl ScriptRunExplanation {
+    /// First runner that can run the script is selected,
+    /// unless candidates already come with a selection, see [`CombinedScriptRunner`]
+    pub fn new(mut candidates: Vec<ScriptRunnerCandidate>) -> Self {
+        if !candidates.iter().any(|candidate| candidate.selected)
+            && let Some(candidate) = candidates.iter_mut().find(|candidate| candidate.can_run)
+        {
+            candidate.selected = true;
+        }
+
+        let selected_runner_id = candidates
+            .iter()
+            .find(|candidate| candidate.selected)
+            .map(|candidate| candidate.runner_id.clone());
+
+        ScriptRunExplanation {
+            candidates,
+            selected_runner_id,
+        }
+    }
 }
 
 pub trait ScriptRunner {
+    fn get_id(&self) -> &str;
     fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool>;
     fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult>;
+
+    /// Reports every runner consulted for the script, without executing anything
+    fn explain_script(&self, context: &ScriptRunContext) -> Vec<ScriptRunnerCandidate> {
+        vec![ScriptRunnerCandidate::probe(self, context)]
+    }
+
+    /// Ids of every runner that may be consulted, as used in runner preferences
+    fn get_runner_ids(&self) -> Vec<&str> {
+        vec![self.get_id()]
+    }
 }
 
 impl ScriptRunResult {
@@ -55,6 +181,22 @@ impl ScriptRunResult {
             exit_code: 0,
             stdout: String::from(""),
             stderr: String::from(""),
+            reason: None,
+        }
+    }
+
+    /// Script could not be started, e.g. the tool is not installed
+    pub fn error(message: String) -> ScriptRunResult {
+        ScriptRunResult {
+            result_type: ScriptRunResultType::Error(message),
+            ..ScriptRunResult::noop()
+        }
+    }
+
+    pub fn skipped(reason: String) -> ScriptRunResult {
+        ScriptRunResult {
+            reason: Some(reason),
+            ..ScriptRunResult::noop()
         }
     }
 }
@@ -62,6 +204,10 @@ impl ScriptRunResult {
 pub struct NoopScriptRunner {}
 
 impl ScriptRunner for NoopScriptRunner {
+    fn get_id(&self) -> &str {
+        "noop"
+    }
+
     fn can_run_script(&self, _context: &ScriptRunContext) -> Result<bool> {
         Ok(false)
     }
@@ -72,7 +218,7 @@ impl ScriptRunner for NoopScriptRunner {
 }
 
 pub(crate) struct CommandRunner {
-    program: String,
+    pub program: String,
 }
 
 impl CommandRunner {
@@ -80,43 +226,19 @@ impl CommandRunner {
         Self { program }
     }
 
-    pub fn can_run_script_dry_run(
-        &self,
-        dry_run_args: Vec<String>,
-        context: &ScriptRunContext,
-    ) -> Result<bool> {
-        let context_path = &context.package.absolute_path;
-        let output = Command::new(&self.program)
-            .args(dry_run_args)
-            .current_dir(context_path)
-            .output()?;
-
-        Ok(matches!(output.status.code(), Some(0)))
-    }
-
-    pub fn exec_command_json(
+    pub fn run_script(
         &self,
-        args: Vec<String>,
+        run_script_args: Vec<String>,
         context: &ScriptRunContext,
-    ) -> Result<Value> {
-        let context_path = &context.package.absolute_path;
-        let output = Command::new(&self.program)
-            .args(args)
-            .current_dir(context_path)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .output()?;
-
-        let stdout = String::from_utf8(output.stdout).unwrap_or_default();
-
-        let json: Value = serde_json::from_str(&stdout)?;
-
-        Ok(json)
+    ) -> Result<ScriptRunResult> {
+        self.run_script_with_input(run_script_args, None, context)
     }
 
-    pub fn run_script(
+    /// Same as `run_script`, writing `input` to the standard input of the command
+    pub fn run_script_with_input(
         &self,
         run_script_args: Vec<String>,
+        input: Option<&str>,
         context: &ScriptRunContext,
     ) -> Result<ScriptRunResult> {
         let command_desc = format!("{} {}", self.program, run_script_args.join(" "));
@@ -127,9 +249,20 @@ impl CommandRunner {
         let mut child = Command::new(&self.program)
             .args(run_script_args)
             .current_dir(context_path)
+            .stdin(if input.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::inherit()
+            })
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .spawn()?;
+            .spawn()
+            .with_context(|| format!("Failed to start {}", command_desc))?;
+
+        if let (Some(input), Some(mut stdin)) = (input, child.stdin.take()) {
+            // Dropping stdin closes it, so the command sees the end of input
+            stdin.write_all(input.as_bytes())?;
+        }
 
         let stdout = child.stdout.take().with_context(|| {
             format!(
@@ -165,6 +298,7 @@ impl CommandRunner {
             exit_code: output.status.code().unwrap_or_default(),
             stdout: stdout_lines,
             stderr: String::from_utf8(output.stderr).unwrap_or_default(),
+            reason: None,
         })
     }
 }
@@ -185,19 +319,141 @@ impl MakeScriptRunner {
             make_runner: CommandRunner::new("make".to_string()),
         }
     }
+
+    /// Make database is read once per package, see [`ScriptRunCache`]
+    fn has_target(&self, context: &ScriptRunContext) -> Result<bool> {
+        let package_path = &context.package.absolute_path;
+        let targets = context
+            .cache
+            .get_or_discover(self.get_id(), package_path, || {
+                Ok(
+                    discover_make_targets(&self.make_runner.program, package_path)?
+                        .into_iter()
+                        .filter(MakeTarget::is_script)
+                        .map(|target| target.name)
+                        .collect(),
+                )
+            })?;
+
+        Ok(targets.iter().any(|target| target == context.script_spec))
+    }
 }
 
 impl ScriptRunner for MakeScriptRunner {
+    fn get_id(&self) -> &str {
+        "make"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let mut args = vec![context.script_spec.to_string()];
+        // Make treats extra arguments as more targets, so they are passed as `$(ARGS)`
+        if !context.script_args.is_empty() {
+            args.push(format!("ARGS={}", context.script_args.join(" ")));
+        }
+
+        self.make_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        self.has_target(context)
+    }
+}
+
+pub struct JustScriptRunner {
+    just_runner: CommandRunner,
+}
+
+impl Default for JustScriptRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JustScriptRunner {
+    pub fn new() -> Self {
+        Self {
+            just_runner: CommandRunner::new("just".to_string()),
+        }
+    }
+
+    /// Fails when recipes cannot be listed, e.g. `just` is not installed
+    fn has_recipe(&self, context: &ScriptRunContext) -> Result<bool> {
+        let recipes =
+            discover_just_recipes(&self.just_runner.program, &context.package.absolute_path)?;
+
+        Ok(recipes.iter().any(|recipe| recipe == context.script_spec))
+    }
+}
+
+impl ScriptRunner for JustScriptRunner {
+    fn get_id(&self) -> &str {
+        "just"
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let mut args = vec![context.script_spec.to_string()];
+        args.extend(context.script_args.iter().cloned());
+
+        self.just_runner.run_script(args, context)
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        self.has_recipe(context)
+    }
+}
+
+/// Runs go-task tasks from package Taskfile
+pub struct TaskfileScriptRunner {
+    task_runner: CommandRunner,
+}
+
+impl Default for TaskfileScriptRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskfileScriptRunner {
+    pub fn new() -> Self {
+        Self {
+            task_runner: CommandRunner::new("task".to_string()),
+        }
+    }
+}
+
+impl ScriptRunner for TaskfileScriptRunner {
+    fn get_id(&self) -> &str {
+        "task"
+    }
+
     fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
-        self.make_runner
-            .run_script(vec![context.script_spec.to_string()], context)
+        let mut args = vec![context.script_spec.to_string()];
+        // Taskfile receives extra arguments as `{{.CLI_ARGS}}` only after `--`
+        if !context.script_args.is_empty() {
+            args.push("--".to_string());
+            args.extend(context.script_args.iter().cloned());
+        }
+
+        self.task_runner.run_script(args, context)
     }
 
     fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
-        self.make_runner.can_run_script_dry_run(
-            vec![context.script_spec.to_string(), "--dry-run".to_string()],
-            context,
-        )
+        // Taskfile is plain YAML, so tasks are read without `task` CLI
+        let tasks = discover_taskfile_tasks(&context.package.absolute_path)?;
+
+        Ok(tasks.iter().any(|task| task == context.script_spec))
+    }
+}
+
+/// Runner that fails to answer is skipped in favor of the next one,
+/// same as [`ScriptRunExplanation`] reports it
+fn can_run_or_skip(runner: &dyn ScriptRunner, context: &ScriptRunContext) -> bool {
+    match runner.can_run_script(context) {
+        Ok(can_run) => can_run,
+        Err(err) => {
+            warn!("Runner `{}` skipped: {:#}", runner.get_id(), err);
+            false
+        }
     }
 }
 
@@ -207,39 +463,195 @@ pub struct WrapperScriptRunner {
 
 impl WrapperScriptRunner {
     pub fn wrap_with_generic_runners(runner: Box<dyn ScriptRunner>) -> Self {
+        Self::wrap_all_with_generic_runners(vec![runner])
+    }
+
+    pub fn wrap_all_with_generic_runners(runners: Vec<Box<dyn ScriptRunner>>) -> Self {
+        let mut wrapper = Self::generic_runners();
+        wrapper.runners.extend(runners);
+        wrapper
+    }
+
+    /// Puts the runner ahead of the generic runners, for scripts the package declares
+    /// for MRT itself
+    pub fn with_first(mut self, runner: Box<dyn ScriptRunner>) -> Self {
+        self.runners.insert(0, runner);
+        self
+    }
+
+    pub fn generic_runners() -> Self {
         // Runners should be from generic to specific
         // So for example `make` is considered to be more generic
         Self {
-            runners: vec![Box::new(MakeScriptRunner::new()), runner],
+            runners: vec![
+                Box::new(MakeScriptRunner::new()),
+                Box::new(JustScriptRunner::new()),
+                Box::new(TaskfileScriptRunner::new()),
+            ],
         }
     }
 
-    pub fn generic_runners() -> Self {
-        Self {
-            runners: vec![Box::new(MakeScriptRunner::new())],
+    /// Reorder and disable runners according to the manifest preferences
+    pub fn with_preferences(mut self, preferences: &RunnerPreferences) -> Self {
+        self.runners
+            .retain(|runner| !preferences.disabled.iter().any(|id| id == runner.get_id()));
+
+        if let Some(order) = &preferences.order {
+            // Stable sort keeps default order for runners not mentioned in preferences
+            self.runners.sort_by_key(|runner| {
+                order
+                    .iter()
+                    .position(|id| id == runner.get_id())
+                    .unwrap_or(order.len())
+            });
         }
+
+        self
     }
 }
 
 impl ScriptRunner for WrapperScriptRunner {
+    fn get_id(&self) -> &str {
+        "wrapper"
+    }
+
     fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(self
+            .runners
+            .iter()
+            .any(|runner| can_run_or_skip(runner.as_ref(), context)))
+    }
+
+    fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
         for runner in &self.runners {
-            if runner.can_run_script(context)? {
-                return Ok(true);
+            if can_run_or_skip(runner.as_ref(), context) {
+                return runner.run_script(context);
             }
         }
 
-        Ok(false)
+        Ok(ScriptRunResult::noop())
+    }
+
+    fn explain_script(&self, context: &ScriptRunContext) -> Vec<ScriptRunnerCandidate> {
+        self.runners
+            .iter()
+            .flat_map(|runner| runner.explain_script(context))
+            .collect()
+    }
+
+    fn get_runner_ids(&self) -> Vec<&str> {
+        self.runners
+            .iter()
+            .flat_map(|runner| runner.get_runner_ids())
+            .collect()
+    }
+}
+
+/// Runs the script with every runner that can run it, used for packages with several
+/// archetypes. Runner selected by more than one of them, like `make`, runs once
+pub struct CombinedScriptRunner {
+    runners: Vec<Box<dyn ScriptRunner>>,
+}
+
+impl CombinedScriptRunner {
+    pub fn new(runners: Vec<Box<dyn ScriptRunner>>) -> Self {
+        Self { runners }
+    }
+}
+
+impl ScriptRunner for CombinedScriptRunner {
+    fn get_id(&self) -> &str {
+        "combined"
+    }
+
+    fn can_run_script(&self, context: &ScriptRunContext) -> Result<bool> {
+        Ok(self
+            .runners
+            .iter()
+            .any(|runner| can_run_or_skip(runner.as_ref(), context)))
     }
 
     fn run_script(&self, context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        let mut executed_runner_ids: Vec<String> = vec![];
+        let mut results: Vec<ScriptRunResult> = vec![];
+
         for runner in &self.runners {
-            if runner.can_run_script(context)? {
-                return runner.run_script(context);
+            let explanation = ScriptRunExplanation::new(runner.explain_script(context));
+            let Some(runner_id) = explanation.selected_runner_id else {
+                continue;
+            };
+            if executed_runner_ids.contains(&runner_id) {
+                continue;
             }
+
+            results.push(runner.run_script(context)?);
+            executed_runner_ids.push(runner_id);
         }
 
-        Ok(ScriptRunResult::noop())
+        if results.is_empty() {
+            return Ok(ScriptRunResult::noop());
+        }
+
+        // Every runner is executed, the first failure is reported
+        let failed = results
+            .iter()
+            .find(|result| !result.result_type.is_success() && !result.result_type.is_noop());
+        let (result_type, exit_code) = match failed {
+            Some(result) => (result.result_type.clone(), result.exit_code),
+            None => (ScriptRunResultType::Success, 0),
+        };
+
+        let join = |field: fn(&ScriptRunResult) -> &str, separator: &str| {
+            results
+                .iter()
+                .map(field)
+                .filter(|value| !value.is_empty())
+                .collect::<Vec<_>>()
+                .join(separator)
+        };
+
+        Ok(ScriptRunResult {
+            command: join(|result| &result.command, "; "),
+            result_type,
+            exit_code,
+            stdout: join(|result| &result.stdout, "\n"),
+            stderr: join(|result| &result.stderr, "\n"),
+            reason: None,
+        })
+    }
+
+    fn explain_script(&self, context: &ScriptRunContext) -> Vec<ScriptRunnerCandidate> {
+        let mut candidates: Vec<ScriptRunnerCandidate> = vec![];
+
+        for runner in &self.runners {
+            let explanation = ScriptRunExplanation::new(runner.explain_script(context));
+
+            for candidate in explanation.candidates {
+                match candidates
+                    .iter_mut()
+                    .find(|existing| existing.runner_id == candidate.runner_id)
+                {
+                    Some(existing) => existing.selected |= candidate.selected,
+                    None => candidates.push(candidate),
+                }
+            }
+        }
+
+        candidates
+    }
+
+    fn get_runner_ids(&self) -> Vec<&str> {
+        let mut runner_ids: Vec<&str> = vec![];
+        for runner_id in self
+            .runners
+            .iter()
+            .flat_map(|runner| runner.get_runner_ids())
+        {
+            if !runner_ids.contains(&runner_id) {
+                runner_ids.push(runner_id);
+            }
+        }
+        runner_ids
     }
 }
 
@@ -252,8 +664,10 @@ fn test_make_script_runner() -> anyhow::Result<()> {
     let runner = MakeScriptRunner::new();
     let context = ScriptRunContext {
         script_spec: "format",
+        script_args: &[],
         package: &package,
         reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
     };
 
     assert!(runner.can_run_script(&context)?);
@@ -269,6 +683,34 @@ fn test_make_script_runner() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_make_script_runner_with_args() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/make-lib5"))?;
+    let runner = MakeScriptRunner::new();
+    let script_args = vec!["--watch".to_string(), "--verbose".to_string()];
+    let context = ScriptRunContext {
+        script_spec: "format",
+        script_args: &script_args,
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
+    };
+
+    let result = runner.run_script(&context)?;
+
+    assert_eq!(result.command, "make format ARGS=--watch --verbose");
+    assert!(
+        result.result_type.is_success(),
+        "stderr: {:?}",
+        result.stderr
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_make_script_runner_run_no_script() -> anyhow::Result<()> {
     let project_path =
@@ -278,10 +720,180 @@ fn test_make_script_runner_run_no_script() -> anyhow::Result<()> {
     let runner = MakeScriptRunner::new();
     let context = ScriptRunContext {
         script_spec: "no-such-script",
+        script_args: &[],
         package: &package,
         reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
     };
     assert!(!runner.can_run_script(&context)?);
 
     Ok(())
 }
+
+#[test]
+fn test_wrapper_script_runner_explain() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/py-lib2"))?;
+    let runner = WrapperScriptRunner::generic_runners();
+    let context = ScriptRunContext {
+        script_spec: "format",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
+    };
+
+    let explanation = ScriptRunExplanation::new(runner.explain_script(&context));
+
+    assert_eq!(explanation.candidates.len(), 3);
+    assert!(explanation.candidates[0].can_run);
+    assert!(!explanation.candidates[1].can_run);
+    assert!(!explanation.candidates[2].can_run);
+    assert_eq!(explanation.selected_runner_id.as_deref(), Some("make"));
+
+    Ok(())
+}
+
+#[test]
+fn test_wrapper_script_runner_with_preferences() {
+    let runner = WrapperScriptRunner::wrap_with_generic_runners(Box::new(NoopScriptRunner {}));
+    assert_eq!(
+        runner.get_runner_ids(),
+        vec!["make", "just", "task", "noop"]
+    );
+
+    let runner = runner.with_preferences(&RunnerPreferences {
+        order: Some(vec!["noop".to_string()]),
+        disabled: vec![],
+    });
+    assert_eq!(
+        runner.get_runner_ids(),
+        vec!["noop", "make", "just", "task"]
+    );
+
+    let runner = runner.with_preferences(&RunnerPreferences {
+        order: None,
+        disabled: vec!["make".to_string(), "just".to_string(), "task".to_string()],
+    });
+    assert_eq!(runner.get_runner_ids(), vec!["noop"]);
+}
+
+#[test]
+fn test_combined_script_runner_runs_shared_runner_once() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/make-lib5"))?;
+    let runner = CombinedScriptRunner::new(vec![
+        Box::new(WrapperScriptRunner::generic_runners()),
+        Box::new(WrapperScriptRunner::wrap_with_generic_runners(Box::new(
+            NoopScriptRunner {},
+        ))),
+    ]);
+    let context = ScriptRunContext {
+        script_spec: "format",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
+    };
+
+    let explanation = ScriptRunExplanation::new(runner.explain_script(&context));
+    let runner_ids: Vec<&str> = explanation
+        .candidates
+        .iter()
+        .map(|candidate| candidate.runner_id.as_str())
+        .collect();
+    assert_eq!(runner_ids, vec!["make", "just", "task", "noop"]);
+    assert_eq!(explanation.selected_runner_id.as_deref(), Some("make"));
+
+    let result = runner.run_script(&context)?;
+    assert!(result.result_type.is_success());
+    assert_eq!(result.command, "make format");
+
+    Ok(())
+}
+
+#[test]
+fn test_just_script_runner_fails_when_just_fails() -> anyhow::Result<()> {
+    let fixture_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("fixures/task-runners");
+    let package = Package::from_package_path(
+        fixture_path.clone(),
+        fixture_path.canonicalize()?,
+        &crate::archetypes::ArchetypeRegistry::new(),
+        &crate::manifest::Manifest::new(),
+    )?;
+    let runner = JustScriptRunner {
+        just_runner: CommandRunner::new("mrt-no-such-just".to_string()),
+    };
+    let context = ScriptRunContext {
+        script_spec: "build",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
+    };
+
+    let err = runner.can_run_script(&context).unwrap_err();
+    assert!(format!("{:#}", err).contains("Failed to list just recipes"));
+
+    // Wrapping runners skip it and try the next runner
+    assert!(!can_run_or_skip(&runner, &context));
+
+    Ok(())
+}
+
+#[cfg(test)]
+struct FailingScriptRunner {}
+
+#[cfg(test)]
+impl ScriptRunner for FailingScriptRunner {
+    fn get_id(&self) -> &str {
+        "failing"
+    }
+
+    fn can_run_script(&self, _context: &ScriptRunContext) -> Result<bool> {
+        anyhow::bail!("Cannot list scripts")
+    }
+
+    fn run_script(&self, _context: &ScriptRunContext) -> Result<ScriptRunResult> {
+        anyhow::bail!("Cannot run scripts")
+    }
+}
+
+#[test]
+fn test_wrapper_script_runner_skips_failing_runner() -> anyhow::Result<()> {
+    let project_path =
+        crate::testing::utils::get_repo_root().join("./references/basic-sample/mrt.yml");
+    let project = crate::project::Project::read(Some(project_path))?;
+    let package = project.read_package(std::path::PathBuf::from("./packages/make-lib5"))?;
+    let runner = CombinedScriptRunner::new(vec![Box::new(WrapperScriptRunner {
+        runners: vec![
+            Box::new(FailingScriptRunner {}),
+            Box::new(MakeScriptRunner::new()),
+        ],
+    })]);
+    let context = ScriptRunContext {
+        script_spec: "format",
+        script_args: &[],
+        package: &package,
+        reporter: &crate::progress::LogProgressReporter {},
+        cache: &ScriptRunCache::default(),
+    };
+
+    let explanation = ScriptRunExplanation::new(runner.explain_script(&context));
+    assert!(explanation.candidates[0].error.is_some());
+    assert_eq!(explanation.selected_runner_id.as_deref(), Some("make"));
+
+    // Runs what the explanation selects
+    assert!(runner.can_run_script(&context)?);
+    let result = runner.run_script(&context)?;
+    assert_eq!(result.command, "make format");
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_yaml_ng::Value;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+// Same lookup order as go-task
+const TASKFILE_NAMES: [&str; 8] = [
+    "Taskfile.yml",
+    "taskfile.yml",
+    "Taskfile.yaml",
+    "taskfile.yaml",
+    "Taskfile.dist.yml",
+    "taskfile.dist.yml",
+    "Taskfile.dist.yaml",
+    "taskfile.dist.yaml",
+];
+
+#[derive(Deserialize, Debug)]
+struct Taskfile {
+    #[serde(default)]
+    tasks: BTreeMap<String, Value>,
+}
+
+pub(crate) fn find_taskfile(package_path: &Path) -> Option<PathBuf> {
+    TASKFILE_NAMES
+        .iter()
+        .map(|name| package_path.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Names and aliases of tasks that can be called from the command line,
+/// tasks from `includes` are not resolved
+pub(crate) fn discover_taskfile_tasks(package_path: &Path) -> Result<Vec<String>> {
+    let Some(taskfile_path) = find_taskfile(package_path) else {
+        return Ok(vec![]);
+    };
+
+    let content = std::fs::read_to_string(&taskfile_path)
+        .with_context(|| format!("Failed to read Taskfile at {}", taskfile_path.display()))?;
+
+    let taskfile: Taskfile = serde_yaml_ng::from_str(&content)
+        .with_context(|| format!("Failed to parse Taskfile at {}", taskfile_path.display()))?;
+
+    Ok(taskfile
+        .tasks
+        .into_iter()
+        // Short syntax, e.g. `build: go build`, has neither aliases nor `internal`
+        .filter(|(_, task)| task["internal"].as_bool() != Some(true))
+        .flat_map(|(name, task)| {
+            let aliases = task["aliases"]
+                .as_sequence()
+                .map(|aliases| {
+                    aliases
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_else(Vec::new);
+
+            std::iter::once(name).chain(aliases)
+        })
+        .collect())
+}
+
+#[test]
+fn test_discover_taskfile_tasks() -> anyhow::Result<()> {
+    let fixture_path = std::path::PathBuf::from(file!())
+        .parent()
+        .unwrap()
+        .join("fixures/task-runners");
+
+    let tasks = discover_taskfile_tasks(&fixture_path)?;
+
+    assert_eq!(tasks, vec!["build", "b", "lint", "test"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_discover_taskfile_tasks_no_taskfile() -> anyhow::Result<()> {
+    let tasks = discover_taskfile_tasks(Path::new("does-not-exist"))?;
+
+    assert!(tasks.is_empty());
+
+    Ok(())
+}
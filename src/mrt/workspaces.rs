@@ -0,0 +1,159 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::path::Path;
+
+/// Workspace definitions package globs can be imported from
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkspaceSource {
+    /// `workspaces` field of the root package.json, used by npm, Yarn and Bun
+    Npm,
+    /// `packages` of pnpm-workspace.yaml
+    Pnpm,
+    /// `[workspace] members` of the root Cargo.toml
+    Cargo,
+}
+
+#[derive(Deserialize, Debug)]
+struct PnpmWorkspace {
+    #[serde(default)]
+    packages: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct CargoManifest {
+    workspace: Option<CargoWorkspace>,
+}
+
+#[derive(Deserialize, Debug)]
+struct CargoWorkspace {
+    #[serde(default)]
+    members: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
+impl WorkspaceSource {
+    /// Package globs declared by the workspace definition, exclusions are prefixed with `!`
+    pub fn read_package_globs(&self, root_path: &Path) -> Result<Vec<String>> {
+        match self {
+            WorkspaceSource::Npm => read_npm_workspaces(root_path),
+            WorkspaceSource::Pnpm => read_pnpm_workspace(root_path),
+            WorkspaceSource::Cargo => read_cargo_workspace(root_path),
+        }
+    }
+}
+
+fn read_npm_workspaces(root_path: &Path) -> Result<Vec<String>> {
+    let package_json_path = root_path.join("package.json");
+    let content = std::fs::read_to_string(&package_json_path).with_context(|| {
+        format!(
+            "Failed to read package.json file at {}",
+            package_json_path.display()
+        )
+    })?;
+    let package_json: Value = serde_json::from_str(&content).with_context(|| {
+        format!(
+            "Failed to parse JSON of package.json file at {}",
+            package_json_path.display()
+        )
+    })?;
+
+    // Either `"workspaces": [...]` or Yarn classic `"workspaces": { "packages": [...] }`
+    let workspaces = match &package_json["workspaces"] {
+        Value::Object(workspaces) => workspaces.get("packages").cloned(),
+        workspaces => Some(workspaces.clone()),
+    };
+
+    Ok(workspaces
+        .as_ref()
+        .and_then(Value::as_array)
+        .map(|globs| {
+            globs
+                .iter()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+fn read_pnpm_workspace(root_path: &Path) -> Result<Vec<String>> {
+    let workspace_path = root_path.join("pnpm-workspace.yaml");
+    let content = std::fs::read_to_string(&workspace_path).with_context(|| {
+        format!(
+            "Failed to read pnpm-workspace.yaml file at {}",
+            workspace_path.display()
+        )
+    })?;
+
+    let workspace: PnpmWorkspace = serde_yaml_ng::from_str(&content).with_context(|| {
+        format!(
+            "Failed to parse pnpm-workspace.yaml file at {}",
+            workspace_path.display()
+        )
+    })?;
+
+    Ok(workspace.packages)
+}
+
+fn read_cargo_workspace(root_path: &Path) -> Result<Vec<String>> {
+    let cargo_toml_path = root_path.join("Cargo.toml");
+    let content = std::fs::read_to_string(&cargo_toml_path).with_context(|| {
+        format!(
+            "Failed to read Cargo.toml file at {}",
+            cargo_toml_path.display()
+        )
+    })?;
+
+    let manifest: CargoManifest = toml::from_str(&content).with_context(|| {
+        format!(
+            "Failed to parse Cargo.toml file at {}",
+            cargo_toml_path.display()
+        )
+    })?;
+
+    Ok(manifest
+        .workspace
+        .map(|workspace| {
+            workspace
+                .members
+                .into_iter()
+                .chain(workspace.exclude.iter().map(|glob| format!("!{}", glob)))
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+#[cfg(test)]
+fn get_sample_path() -> std::path::PathBuf {
+    crate::testing::utils::get_repo_root().join("./references/workspaces-sample")
+}
+
+#[test]
+fn test_read_npm_workspaces() -> anyhow::Result<()> {
+    let globs = WorkspaceSource::Npm.read_package_globs(&get_sample_path())?;
+
+    assert_eq!(globs, vec!["libs/*"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_read_pnpm_workspace() -> anyhow::Result<()> {
+    let globs = WorkspaceSource::Pnpm.read_package_globs(&get_sample_path())?;
+
+    assert_eq!(globs, vec!["tools/*", "!tools/ignored"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_read_cargo_workspace() -> anyhow::Result<()> {
+    let globs = WorkspaceSource::Cargo.read_package_globs(&get_sample_path())?;
+
+    assert_eq!(globs, vec!["crates/*", "!crates/ignored"]);
+
+    Ok(())
+}